@@ -1,5 +1,7 @@
 use crate::backlog::BacklogClient;
-use crate::db::{DbClient, WorkspaceInput};
+use crate::db::{DbClient, SortKey, WorkspaceInput};
+use crate::scoring::{ParentIssueMode, ScoringService};
+use futures::StreamExt;
 use serde::Serialize;
 use tauri::State;
 
@@ -143,31 +145,286 @@ pub async fn save_settings(
     value: String,
     db: State<'_, DbClient>,
 ) -> Result<(), String> {
+    // プロキシは検証してから即時に反映する（無効なURLは保存しない）
+    if key == crate::backlog::SETTING_PROXY_URL {
+        crate::backlog::set_proxy_url(&value)?;
+    }
+
     db.save_setting(&key, &value)
         .await
         .map_err(|e| e.to_string())?;
 
     if key == "language" {
-        let issues = db.get_issues().await.map_err(|e| e.to_string())?;
-        let high_priority_count = issues.iter().filter(|i| i.relevance_score >= 80).count();
+        refresh_tray_for_language(&app, &db, &value).await?;
+    }
+    // 自動同期の切り替え・同期間隔は、スケジューラーを起動し直して次の同期から反映する
+    if crate::scheduler::restarts_scheduler(&key) {
+        crate::scheduler::restart(&app);
+    }
 
-        // 言語設定を取得（デフォルトは日本語）
-        let lang = value;
+    Ok(())
+}
 
-        if let Some(tray) = app.tray_by_id("main") {
-            let tooltip = if high_priority_count > 0 {
-                if lang == "ja" {
-                    format!("ProjectLens: 重要なチケットが {high_priority_count} 件あります")
-                } else {
-                    format!("ProjectLens: {high_priority_count} important tickets")
-                }
-            } else {
-                "ProjectLens".to_string()
-            };
-            let _ = tray.set_tooltip(Some(tooltip));
+/// 複数の設定をまとめて保存
+///
+/// 設定画面の一括保存用。全ての設定を1つのトランザクションで保存し、途中で失敗した場合は
+/// いずれの設定も更新しない。プロキシの適用・言語変更によるトレイ更新は保存の成功後に一度だけ行う。
+///
+/// # 引数
+/// * `settings` - 設定のキーと値
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、失敗時はエラーメッセージ
+#[tauri::command]
+pub async fn save_settings_batch(
+    app: tauri::AppHandle,
+    settings: std::collections::HashMap<String, String>,
+    db: State<'_, DbClient>,
+) -> Result<(), String> {
+    // プロキシは保存前に検証する（無効なURLが含まれていれば何も保存しない）
+    let proxy_url = settings.get(crate::backlog::SETTING_PROXY_URL);
+    if let Some(proxy_url) = proxy_url {
+        crate::backlog::parse_proxy_url(proxy_url)?;
+    }
+
+    db.save_settings(&settings)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if let Some(proxy_url) = proxy_url {
+        crate::backlog::set_proxy_url(proxy_url)?;
+    }
+    if let Some(language) = settings.get("language") {
+        refresh_tray_for_language(&app, &db, language).await?;
+    }
+    if settings.keys().any(|key| crate::scheduler::restarts_scheduler(key)) {
+        crate::scheduler::restart(&app);
+    }
+
+    Ok(())
+}
+
+/// 表示言語の設定キー
+const SETTING_LANGUAGE: &str = "language";
+
+/// 対応している表示言語
+const SUPPORTED_LANGUAGES: [&str; 2] = ["ja", "en"];
+
+/// 自分の課題のみ表示するかの設定キー
+const SETTING_SHOW_ONLY_MY_ISSUES: &str = "show_only_my_issues";
+
+/// 設定画面で使う主要な設定（[`get_app_config`] / [`save_app_config`]）
+///
+/// 設定ごとに `get_settings` を往復しなくて済むよう、まとめて取得・保存する。
+/// 未設定・不正な項目は既定値で埋め、デシリアライズ時に省略された項目も既定値にする。
+#[derive(Debug, Clone, PartialEq, Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    /// 表示言語（`ja` / `en`）
+    pub language: String,
+    /// バックグラウンドの定期同期を行うか
+    pub auto_sync_enabled: bool,
+    /// 定期同期の最短間隔（分）
+    pub sync_interval_minutes: u64,
+    /// 高スコアとして通知・件数表示する基準
+    pub notification_threshold: i32,
+    /// 課題取得対象の期間（日数。`None` で無制限）
+    pub fetch_period_days: Option<i64>,
+    /// 自分の課題のみ表示するか
+    pub show_only_my_issues: bool,
+    /// 同期を見送るレート残量のしきい値
+    pub rate_limit_skip_threshold: i64,
+    /// 同期全体のタイムアウト（秒）
+    pub sync_timeout_secs: u64,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            language: "ja".to_string(),
+            auto_sync_enabled: true,
+            sync_interval_minutes: crate::scheduler::DEFAULT_SYNC_INTERVAL_MINUTES,
+            notification_threshold: crate::scheduler::DEFAULT_NOTIFICATION_THRESHOLD,
+            fetch_period_days: None,
+            show_only_my_issues: false,
+            rate_limit_skip_threshold: crate::scheduler::DEFAULT_RATE_LIMIT_SKIP_THRESHOLD,
+            sync_timeout_secs: crate::scheduler::DEFAULT_SYNC_TIMEOUT_SECS,
+        }
+    }
+}
+
+impl AppConfig {
+    /// 保存済みの設定から組み立てる（未設定・不正値は既定値）
+    ///
+    /// # 引数
+    /// * `db` - データベースクライアント
+    ///
+    /// # 戻り値
+    /// 主要な設定
+    async fn load(db: &DbClient) -> Self {
+        let defaults = Self::default();
+        Self {
+            language: db
+                .get_setting_or(SETTING_LANGUAGE, defaults.language)
+                .await
+                .ok()
+                .filter(|lang| SUPPORTED_LANGUAGES.contains(&lang.as_str()))
+                .unwrap_or_else(|| "ja".to_string()),
+            auto_sync_enabled: crate::scheduler::is_auto_sync_enabled(db).await,
+            sync_interval_minutes: crate::scheduler::resolve_sync_interval(db).await.as_secs()
+                / 60,
+            notification_threshold: crate::scheduler::resolve_notification_threshold(db).await,
+            fetch_period_days: crate::scheduler::resolve_fetch_period_days(db).await,
+            show_only_my_issues: db
+                .get_setting_or(SETTING_SHOW_ONLY_MY_ISSUES, defaults.show_only_my_issues)
+                .await
+                .unwrap_or(false),
+            rate_limit_skip_threshold: crate::scheduler::resolve_rate_limit_skip_threshold(db)
+                .await,
+            sync_timeout_secs: crate::scheduler::resolve_sync_timeout(db).await.as_secs(),
+        }
+    }
+
+    /// 保存できる設定か検証する
+    ///
+    /// # 戻り値
+    /// 妥当なら`Ok(())`、表示言語が未対応・同期間隔が 0 の場合はエラーメッセージ
+    fn validate(&self) -> Result<(), String> {
+        if !SUPPORTED_LANGUAGES.contains(&self.language.as_str()) {
+            return Err(format!("Unsupported language: {}", self.language));
         }
+        if self.sync_interval_minutes == 0 {
+            return Err("Sync interval must be at least 1 minute".to_string());
+        }
+        Ok(())
+    }
+
+    /// `settings` テーブルへ保存するキーと値に変換する
+    ///
+    /// 取得期間の `None`（無制限）は `"0"` として保存する。
+    fn to_settings(&self) -> std::collections::HashMap<String, String> {
+        let fetch_period_days = self.fetch_period_days.unwrap_or(0);
+        [
+            (SETTING_LANGUAGE, self.language.clone()),
+            (
+                crate::scheduler::SETTING_AUTO_SYNC_ENABLED,
+                self.auto_sync_enabled.to_string(),
+            ),
+            (
+                crate::scheduler::SETTING_SYNC_INTERVAL_MINUTES,
+                self.sync_interval_minutes.to_string(),
+            ),
+            (
+                crate::scheduler::SETTING_NOTIFICATION_THRESHOLD,
+                self.notification_threshold.to_string(),
+            ),
+            (crate::scheduler::SETTING_FETCH_PERIOD_DAYS, fetch_period_days.to_string()),
+            (SETTING_SHOW_ONLY_MY_ISSUES, self.show_only_my_issues.to_string()),
+            (
+                crate::scheduler::SETTING_RATE_LIMIT_SKIP_THRESHOLD,
+                self.rate_limit_skip_threshold.to_string(),
+            ),
+            (crate::scheduler::SETTING_SYNC_TIMEOUT_SECS, self.sync_timeout_secs.to_string()),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect()
     }
+}
+
+/// 主要な設定をまとめて取得
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+///
+/// # 戻り値
+/// 主要な設定（未設定の項目は既定値）
+#[tauri::command]
+pub async fn get_app_config(db: State<'_, DbClient>) -> Result<AppConfig, String> {
+    Ok(AppConfig::load(&db).await)
+}
+
+/// 主要な設定をまとめて保存
+///
+/// 表示言語が `ja` / `en` 以外、または同期間隔が 0 の場合は何も保存せずにエラーを返す。
+/// 全ての項目を1つのトランザクションで保存する。保存後、スケジューラーを起動し直して新しい設定を
+/// 反映し、言語の変更をトレイへ反映する。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `config` - 保存する設定
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、失敗時はエラーメッセージ
+#[tauri::command]
+pub async fn save_app_config(
+    app: tauri::AppHandle,
+    db: State<'_, DbClient>,
+    config: AppConfig,
+) -> Result<(), String> {
+    config.validate()?;
+    db.save_settings(&config.to_settings())
+        .await
+        .map_err(|e| e.to_string())?;
+    crate::scheduler::restart(&app);
+    refresh_tray_for_language(&app, &db, &config.language).await
+}
+
+/// 言語設定の変更をトレイのツールチップ・バッジに反映する
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `db` - データベースクライアント
+/// * `lang` - 新しい言語設定
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、失敗時はエラーメッセージ
+async fn refresh_tray_for_language(
+    app: &tauri::AppHandle,
+    db: &DbClient,
+    lang: &str,
+) -> Result<(), String> {
+    let issues = db.get_issues().await.map_err(|e| e.to_string())?;
+    let thresholds = crate::scheduler::resolve_notification_thresholds(db).await;
+    let high_priority_count = crate::scheduler::count_high_priority(&issues, &thresholds);
+
+    crate::scheduler::update_tray_tooltip(app, lang, high_priority_count);
+    Ok(())
+}
+
+/// 設定用サブウィンドウのラベル（同じラベルのウィンドウは1つだけ存在する）
+pub(crate) const SETTINGS_WINDOW_LABEL: &str = "settings";
 
+/// 設定画面を独立したウィンドウで開く
+///
+/// ラベル [`SETTINGS_WINDOW_LABEL`] のウィンドウが既にあれば新たに作らず、表示してフォーカスする。
+/// ウィンドウを閉じると破棄され、次回は新しく生成する（メインウィンドウと違い常駐させない）。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、失敗時はエラーメッセージ
+#[tauri::command]
+pub async fn open_settings_window(app: tauri::AppHandle) -> Result<(), String> {
+    use tauri::Manager;
+    if let Some(window) = app.get_webview_window(SETTINGS_WINDOW_LABEL) {
+        let _ = window.unminimize();
+        window.show().map_err(|e| e.to_string())?;
+        return window.set_focus().map_err(|e| e.to_string());
+    }
+    tauri::WebviewWindowBuilder::new(
+        &app,
+        SETTINGS_WINDOW_LABEL,
+        tauri::WebviewUrl::App("settings".into()),
+    )
+    .title("ProjectLens - Settings")
+    .inner_size(800.0, 700.0)
+    .resizable(true)
+    .build()
+    .map_err(|e| e.to_string())?;
     Ok(())
 }
 
@@ -186,18 +443,259 @@ pub async fn get_workspace_by_id(
     Ok(workspaces.into_iter().find(|w| w.id == workspace_id))
 }
 
+/// ワークスペースの並び順を変更
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `ordered_ids` - 表示したい順のワークスペースID
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、失敗時はエラーメッセージ
+#[tauri::command]
+pub async fn reorder_workspaces(
+    db: State<'_, DbClient>,
+    ordered_ids: Vec<i64>,
+) -> Result<(), String> {
+    db.reorder_workspaces(&ordered_ids)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// ワークスペースごとのスコアリング設定を取得
+///
+/// [`save_workspace_scoring`] で保存した設定を返す。保存済みの JSON が読めない場合は、スコア計算と
+/// 同じく未設定（グローバルの設定のみ）として扱う。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `workspace_id` - ワークスペースID
+///
+/// # 戻り値
+/// ワークスペースごとの設定（未設定なら `None`）。ワークスペースが存在しない場合はエラーメッセージ
+#[tauri::command]
+pub async fn get_workspace_scoring(
+    db: State<'_, DbClient>,
+    workspace_id: i64,
+) -> Result<Option<crate::scoring::WorkspaceScoringConfig>, String> {
+    let workspaces = db.get_workspaces().await.map_err(|e| e.to_string())?;
+    if !workspaces.iter().any(|w| w.id == workspace_id) {
+        return Err(format!("Workspace not found: {workspace_id}"));
+    }
+    let config = db
+        .get_workspace_scoring_config(workspace_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(config.and_then(|config| serde_json::from_str(&config).ok()))
+}
+
+/// ワークスペースごとのスコアリング設定を保存
+///
+/// 指定した項目だけグローバルの設定を上書きする（[`crate::scoring::WorkspaceScoringConfig`]）。
+/// 次回の同期からスコア計算に反映される。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `workspace_id` - ワークスペースID
+/// * `config_json` - 設定のJSON（例: `{"attachment_bonus": 20}`。空文字でグローバルの設定に戻す）
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、不正なJSONやワークスペースが存在しない場合はエラーメッセージ
+#[tauri::command]
+pub async fn save_workspace_scoring(
+    db: State<'_, DbClient>,
+    workspace_id: i64,
+    config_json: String,
+) -> Result<(), String> {
+    let config_json = config_json.trim();
+    let config = if config_json.is_empty() {
+        None
+    } else {
+        let config: crate::scoring::WorkspaceScoringConfig = serde_json::from_str(config_json)
+            .map_err(|e| format!("Invalid scoring config: {e}"))?;
+        Some(serde_json::to_string(&config).map_err(|e| e.to_string())?)
+    };
+    db.save_workspace_scoring_config(workspace_id, config.as_deref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// ワークスペースごとの通知しきい値を保存
+///
+/// このワークスペースの課題の通知・トレイの件数表示で、グローバルの通知しきい値の代わりに使う。
+/// 範囲外の値は判定時にクランプする。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `workspace_id` - ワークスペースID
+/// * `threshold` - 通知しきい値（未指定でグローバルの設定に戻す）
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、ワークスペースが存在しない場合はエラーメッセージ
+#[tauri::command]
+pub async fn save_workspace_notification_threshold(
+    db: State<'_, DbClient>,
+    workspace_id: i64,
+    threshold: Option<i64>,
+) -> Result<(), String> {
+    db.save_workspace_notification_threshold(workspace_id, threshold)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// スヌーズ期限の文字列を検証し、保存する形（UTC の RFC3339）にそろえる
+///
+/// # 引数
+/// * `until` - スヌーズ期限（RFC3339。例: `2026-10-16T18:00:00+09:00`）
+///
+/// # 戻り値
+/// UTC に変換した RFC3339 文字列。読み取れない場合はエラーメッセージ
+fn normalize_snooze_until(until: &str) -> Result<String, String> {
+    chrono::DateTime::parse_from_rfc3339(until.trim())
+        .map(|until| until.with_timezone(&chrono::Utc).to_rfc3339())
+        .map_err(|e| format!("Invalid snooze time: {until} ({e})"))
+}
+
+/// ワークスペースの定期同期・通知を指定時刻まで止める（スヌーズ）
+///
+/// 無効化と違い、スヌーズ中も課題は削除せずに残し、期限を過ぎると定期同期を再開する。
+/// 手動の同期（[`fetch_issues`] など）は明示的な操作なのでスヌーズ中も行う。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `workspace_id` - ワークスペースID
+/// * `until` - スヌーズ期限（RFC3339）
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、期限が不正・ワークスペースが存在しない場合はエラーメッセージ
+#[tauri::command]
+pub async fn snooze_workspace(
+    db: State<'_, DbClient>,
+    workspace_id: i64,
+    until: String,
+) -> Result<(), String> {
+    let until = normalize_snooze_until(&until)?;
+    db.save_workspace_snoozed_until(Some(workspace_id), Some(&until))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// すべてのワークスペースを指定時刻までスヌーズする
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `until` - スヌーズ期限（RFC3339）
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、期限が不正な場合はエラーメッセージ
+#[tauri::command]
+pub async fn snooze_all(db: State<'_, DbClient>, until: String) -> Result<(), String> {
+    let until = normalize_snooze_until(&until)?;
+    db.save_workspace_snoozed_until(None, Some(&until))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// スヌーズを解除して定期同期を再開する
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `workspace_id` - ワークスペースID（未指定ならすべてのワークスペース）
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、ワークスペースが存在しない場合はエラーメッセージ
+#[tauri::command]
+pub async fn cancel_snooze(
+    db: State<'_, DbClient>,
+    workspace_id: Option<i64>,
+) -> Result<(), String> {
+    db.save_workspace_snoozed_until(workspace_id, None)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// ユーザー変化の確認が必要なときに返すエラーメッセージの接頭辞
+///
+/// フロントエンドはこの接頭辞で判定し、確認後に `confirm_user_change: true` で再実行する。
+pub const USER_CHANGED_ERROR: &str = "USER_CHANGED";
+
+/// 保存済みのユーザーとAPIキーのユーザーが異なれば、確認を求めて保存を拒否する
+///
+/// 変化があれば `user-changed` イベントを発火し、`confirm_user_change` が `true` でない限り
+/// [`USER_CHANGED_ERROR`] で始まるエラーを返す。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル（イベント発火用）
+/// * `workspace` - 保存済みのワークスペース
+/// * `me` - APIキーで取得したユーザー
+/// * `confirm_user_change` - ユーザーの変更を確認済みなら `true`
+///
+/// # 戻り値
+/// 保存してよければ`Ok(())`、確認が必要ならエラーメッセージ
+fn ensure_user_unchanged(
+    app: &tauri::AppHandle,
+    workspace: &crate::db::Workspace,
+    me: &crate::backlog::User,
+    confirm_user_change: Option<bool>,
+) -> Result<(), String> {
+    use tauri::Emitter;
+
+    let Some(change) = crate::scheduler::detect_user_change(
+        workspace.id,
+        &workspace.domain,
+        workspace.user_id,
+        workspace.user_name.as_deref(),
+        me,
+    ) else {
+        return Ok(());
+    };
+    if confirm_user_change == Some(true) {
+        return Ok(());
+    }
+    let _ = app.emit("user-changed", &change);
+    Err(format!(
+        "{USER_CHANGED_ERROR}: user changed from {} to {}",
+        change.previous_user_name.as_deref().unwrap_or("unknown"),
+        change.current_user_name
+    ))
+}
+
+/// ワークスペースを保存
+///
+/// 既存のワークスペースと別ユーザーのAPIキーだった場合は、`confirm_user_change` が `true` で
+/// ない限り保存を拒否する（[`ensure_user_unchanged`]）。
+///
+/// # 引数
+/// * `domain` - Backlogのドメイン
+/// * `api_key` - BacklogのAPIキー
+/// * `project_keys` - 対象プロジェクトキー
+/// * `label` - 表示名（未指定なら既存のラベルを保持し、空文字なら解除してドメインを表示名に使う）
+/// * `confirm_user_change` - ユーザーの変更を確認済みなら `true`
 #[tauri::command]
 pub async fn save_workspace(
+    app: tauri::AppHandle,
     db: State<'_, DbClient>,
     domain: String,
     api_key: String,
     project_keys: Vec<String>,
+    label: Option<String>,
+    confirm_user_change: Option<bool>,
 ) -> Result<(), String> {
+    // 入力ゆれ（プロトコル・末尾スラッシュ・/api/v2 付き）を正規化してから扱う
+    let domain = crate::backlog::normalize_domain(&domain)?;
+
     // Backlog APIクライアントを作成してユーザー情報を取得
-    let client = BacklogClient::new(&domain, &api_key);
+    let client = BacklogClient::new(&domain, &api_key).map_err(|e| e.to_string())?;
     let me = client.get_myself().await.map_err(|e| e.to_string())?;
 
-    let keys_str = project_keys.join(",");
+    let workspaces = db.get_workspaces().await.map_err(|e| e.to_string())?;
+    if let Some(existing) = workspaces.iter().find(|w| w.domain == domain) {
+        ensure_user_unchanged(&app, existing, &me, confirm_user_change)?;
+    }
+
+    let is_new_workspace = !workspaces.iter().any(|w| w.domain == domain);
+
+    // トリム・空要素除去・重複除去したうえで保存する
+    let keys_str = crate::db::normalize_project_keys(&project_keys);
     // 新規ワークスペースはデフォルトで有効
     db.save_workspace(WorkspaceInput {
         domain,
@@ -209,17 +707,97 @@ pub async fn save_workspace(
         api_limit: None,
         api_remaining: None,
         api_reset: None,
+        label: normalize_label(label),
     })
     .await
-    .map_err(|e| e.to_string())
+    .map_err(|e| e.to_string())?;
+
+    // 追加したワークスペースの課題を早く取り込めるよう、定期同期の間隔を最短に戻す
+    if is_new_workspace {
+        use tauri::Manager;
+        app.state::<crate::scheduler::SyncInterval>().reset();
+    }
+    Ok(())
 }
 
-/// ワークスペースの有効・無効を切り替え
+/// ワークスペースのAPIキーを更新
+///
+/// 新しいAPIキーで `get_myself` を呼んで接続を検証し、成功した場合のみAPIキーとユーザー情報を
+/// 更新する。プロジェクトキー等その他の設定は保持する。検証に失敗した場合はDBを変更しない。
+/// 別ユーザーのAPIキーだった場合は、`confirm_user_change` が `true` でない限り更新を拒否する。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル（イベント発火用）
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `workspace_id` - ワークスペースID
+/// * `api_key` - 新しいAPIキー
+/// * `confirm_user_change` - ユーザーの変更を確認済みなら `true`
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、失敗時はエラーメッセージ
 #[tauri::command]
-pub async fn toggle_workspace_enabled(
+pub async fn update_workspace_api_key(
+    app: tauri::AppHandle,
     db: State<'_, DbClient>,
     workspace_id: i64,
-    enabled: bool,
+    api_key: String,
+    confirm_user_change: Option<bool>,
+) -> Result<(), String> {
+    let workspaces = db.get_workspaces().await.map_err(|e| e.to_string())?;
+    let workspace = workspaces
+        .into_iter()
+        .find(|w| w.id == workspace_id)
+        .ok_or_else(|| "Workspace not found".to_string())?;
+
+    // 新しいキーで接続を検証してから保存する
+    let client =
+        BacklogClient::new(&workspace.domain, &api_key).map_err(|e| e.to_string())?;
+    let me = client.get_myself().await.map_err(|e| e.to_string())?;
+    ensure_user_unchanged(&app, &workspace, &me, confirm_user_change)?;
+
+    db.update_workspace_api_key(workspace_id, &api_key, me.id, &me.name)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 保存済みのAPIキーのユーザーをワークスペースのユーザーとして受け入れる
+///
+/// 同期時に `user-changed` イベントで通知されたユーザー変化を、ユーザーが確認した後に呼ぶ。
+/// 保存済みのAPIキーで `get_myself` を呼び直し、ユーザーID・ユーザー名を更新する。
+/// 更新するまで、そのワークスペースの同期は見送られる。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `workspace_id` - ワークスペースID
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、失敗時はエラーメッセージ
+#[tauri::command]
+pub async fn accept_workspace_user_change(
+    db: State<'_, DbClient>,
+    workspace_id: i64,
+) -> Result<(), String> {
+    let workspaces = db.get_workspaces().await.map_err(|e| e.to_string())?;
+    let workspace = workspaces
+        .into_iter()
+        .find(|w| w.id == workspace_id)
+        .ok_or_else(|| "Workspace not found".to_string())?;
+
+    let client = BacklogClient::new(&workspace.domain, &workspace.api_key)
+        .map_err(|e| e.to_string())?;
+    let me = client.get_myself().await.map_err(|e| e.to_string())?;
+
+    db.update_workspace_api_key(workspace_id, &workspace.api_key, me.id, &me.name)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// ワークスペースの有効・無効を切り替え
+#[tauri::command]
+pub async fn toggle_workspace_enabled(
+    db: State<'_, DbClient>,
+    workspace_id: i64,
+    enabled: bool,
 ) -> Result<(), String> {
     let workspaces = db.get_workspaces().await.map_err(|e| e.to_string())?;
     let workspace = workspaces
@@ -237,277 +815,1576 @@ pub async fn toggle_workspace_enabled(
         api_limit: workspace.api_limit,
         api_remaining: workspace.api_remaining,
         api_reset: workspace.api_reset,
+        label: workspace.label,
     })
     .await
     .map_err(|e| e.to_string())
 }
 
+/// ラベル入力を正規化する（前後空白を除去する）。
+///
+/// 未指定 `None` は既存のラベルを保持し、空文字はラベルの解除として保存時に扱われる。
+fn normalize_label(label: Option<String>) -> Option<String> {
+    label.map(|l| l.trim().to_string())
+}
+
 #[tauri::command]
 pub async fn delete_workspace(db: State<'_, DbClient>, id: i64) -> Result<(), String> {
     db.delete_workspace(id).await.map_err(|e| e.to_string())
 }
 
-/// 設定を取得
+/// すべてのデータを削除して初期状態に戻す
+///
+/// 設定・ワークスペース・課題などをすべて削除する（[`DbClient::reset_all_data`]）。
+/// 取り消しできない操作のため、フロント側で確認してから呼び出す前提とする。
+/// 実行後は `data-reset` イベントを発火し、フロントの表示を初期化させる。
+/// メモリ上のプロキシ設定・トレイのツールチップも既定の状態に戻し、ログイン時の自動起動の
+/// OS への登録も解除する。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、失敗時はエラーメッセージ
+#[tauri::command]
+pub async fn reset_all_data(app: tauri::AppHandle, db: State<'_, DbClient>) -> Result<(), String> {
+    use tauri::Emitter;
+
+    db.reset_all_data().await.map_err(|e| e.to_string())?;
+
+    crate::backlog::set_proxy_url("")?;
+    refresh_tray_for_language(&app, &db, "ja").await?;
+    // 設定の記録だけ消えて OS の自動起動が残らないよう、登録も解除する（失敗してもリセットは続行）
+    #[cfg(desktop)]
+    {
+        use tauri_plugin_autostart::ManagerExt;
+
+        if let Err(e) = app.autolaunch().disable() {
+            eprintln!("Failed to disable autostart on reset: {e}");
+        }
+    }
+    let _ = app.emit("data-reset", ());
+    Ok(())
+}
+
+/// 設定を取得
+///
+/// 指定されたキーの設定値をデータベースから取得する。
+///
+/// # 引数
+/// * `key` - 設定のキー
+/// * `default` - キーが存在しない場合に返す値（省略時は`None`を返す）
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+///
+/// # 戻り値
+/// 設定値（存在しない場合は`default`）、またはエラーメッセージ
+#[tauri::command]
+pub async fn get_settings(
+    key: String,
+    default: Option<String>,
+    db: State<'_, DbClient>,
+) -> Result<Option<String>, String> {
+    db.get_setting_with_default(&key, default)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// ログイン時の自動起動の状態を記録する設定キー（`settings` テーブル。`"true"` / `"false"`）
+pub const SETTING_AUTOSTART: &str = "autostart";
+
+/// ログイン時の自動起動を設定
+///
+/// `tauri-plugin-autostart` でOSへ登録・解除し（macOS は LaunchAgent、Windows はレジストリ、
+/// Linux は autostart の .desktop）、結果を `settings` テーブルにも記録する。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル（自動注入）
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `enabled` - 自動起動を有効にするなら `true`
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、登録・解除の失敗や非対応環境ではエラーメッセージ
+#[tauri::command]
+pub async fn set_autostart(
+    app: tauri::AppHandle,
+    db: State<'_, DbClient>,
+    enabled: bool,
+) -> Result<(), String> {
+    #[cfg(desktop)]
+    {
+        use tauri_plugin_autostart::ManagerExt;
+
+        let autolaunch = app.autolaunch();
+        let result = if enabled {
+            autolaunch.enable()
+        } else {
+            autolaunch.disable()
+        };
+        result.map_err(|e| format!("Failed to update autostart: {e}"))?;
+
+        db.save_setting(SETTING_AUTOSTART, &enabled.to_string())
+            .await
+            .map_err(|e| e.to_string())
+    }
+    #[cfg(not(desktop))]
+    {
+        let _ = (app, db, enabled);
+        Err("Autostart is not supported on this platform".to_string())
+    }
+}
+
+/// ログイン時の自動起動の状態を取得
+///
+/// OSへの登録状態を正とし、`settings` テーブルの記録がずれていれば合わせて更新する
+/// （OS の設定画面から解除された場合などにフロントのトグルと同期させるため）。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル（自動注入）
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+///
+/// # 戻り値
+/// 自動起動が有効なら `true`。状態の取得失敗や非対応環境ではエラーメッセージ
+#[tauri::command]
+pub async fn get_autostart(app: tauri::AppHandle, db: State<'_, DbClient>) -> Result<bool, String> {
+    #[cfg(desktop)]
+    {
+        use tauri_plugin_autostart::ManagerExt;
+
+        let enabled = app
+            .autolaunch()
+            .is_enabled()
+            .map_err(|e| format!("Failed to get autostart state: {e}"))?;
+
+        let value = enabled.to_string();
+        let saved = db
+            .get_setting(SETTING_AUTOSTART)
+            .await
+            .map_err(|e| e.to_string())?;
+        if saved.as_deref() != Some(value.as_str()) {
+            db.save_setting(SETTING_AUTOSTART, &value)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(enabled)
+    }
+    #[cfg(not(desktop))]
+    {
+        let _ = (app, db);
+        Err("Autostart is not supported on this platform".to_string())
+    }
+}
+
+/// 設定とワークスペースをJSONでエクスポート
+///
+/// バックアップや別マシンへの移行用に、全ワークスペースと `settings` テーブルの内容を
+/// JSON文字列で返す。APIキーは平文で出力されるため、`include_api_keys` に `true` を
+/// 明示した場合のみ含める（未指定は含めない）。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `include_api_keys` - APIキーを含めるなら `true`
+///
+/// # 戻り値
+/// エクスポートしたJSON文字列、またはエラーメッセージ
+#[tauri::command]
+pub async fn export_settings(
+    db: State<'_, DbClient>,
+    include_api_keys: Option<bool>,
+) -> Result<String, String> {
+    let export = db
+        .export_settings(include_api_keys.unwrap_or(false))
+        .await
+        .map_err(|e| e.to_string())?;
+    serde_json::to_string_pretty(&export).map_err(|e| e.to_string())
+}
+
+/// JSONから設定とワークスペースをインポート
+///
+/// [`export_settings`] の出力を取り込む。`overwrite` に `true` を指定すると既存データを置き換え、
+/// 未指定・`false` のときは既存データとマージする。APIキーを含まないワークスペースは
+/// 既存の同一ドメインのAPIキーを引き継ぎ、既存が無ければスキップする。
+/// 取り込み後のプロキシ設定は、以降に作成する Backlog クライアントへ反映する。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `json` - エクスポートしたJSON文字列
+/// * `overwrite` - 既存データを置き換えるなら `true`
+///
+/// # 戻り値
+/// 取り込み結果の件数、またはエラーメッセージ
+#[tauri::command]
+pub async fn import_settings(
+    db: State<'_, DbClient>,
+    json: String,
+    overwrite: Option<bool>,
+) -> Result<crate::db::ImportSummary, String> {
+    let data: crate::db::SettingsExport =
+        serde_json::from_str(&json).map_err(|e| format!("Invalid settings JSON: {e}"))?;
+    let summary = db
+        .import_settings(&data, overwrite.unwrap_or(false))
+        .await
+        .map_err(|e| e.to_string())?;
+    let proxy_url = db
+        .get_setting(crate::backlog::SETTING_PROXY_URL)
+        .await
+        .map_err(|e| e.to_string())?
+        .unwrap_or_default();
+    crate::backlog::set_proxy_url(&proxy_url)?;
+    Ok(summary)
+}
+
+/// 認証に失敗したワークスペースを無効化し、`workspace-disabled` イベントでフロントへ知らせる
+///
+/// 手動同期用。無効化の条件と内容は定期同期（[`crate::scheduler::disable_workspace_on_auth_failure`]）
+/// と同じ。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル（イベント送信用）
+/// * `db` - データベースクライアント
+/// * `workspace_id` - ワークスペースID
+/// * `domain` - ワークスペースのドメイン
+/// * `error` - 認証エラー
+async fn disable_workspace_on_auth_failure(
+    app: &tauri::AppHandle,
+    db: &DbClient,
+    workspace_id: i64,
+    domain: &str,
+    error: &(dyn std::error::Error + Send + Sync + 'static),
+) {
+    use tauri::Emitter;
+    let disabled =
+        crate::scheduler::disable_workspace_on_auth_failure(db, workspace_id, domain, error).await;
+    if let Some(disabled) = disabled {
+        let _ = app.emit("workspace-disabled", &disabled);
+    }
+}
+
+/// 手動同期でワークスペースの同期を見送った理由（[`sync_workspace_issues`]。前回の課題は残す）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SyncSkipReason {
+    /// 全プロジェクトの取得に失敗した
+    AllProjectsFailed,
+    /// 認証に失敗したためワークスペースを無効化した
+    AuthFailed,
+    /// APIキーのユーザーが保存済みのユーザーと異なる
+    UserChanged,
+    /// ユーザー情報を取得できなかった
+    UserInfoUnavailable,
+}
+
+impl SyncSkipReason {
+    /// ワークスペースごとの同期結果（[`WorkspaceSyncResult::error`]）に載せるメッセージ
+    ///
+    /// # 引数
+    /// * `workspace_id` - ワークスペースID
+    ///
+    /// # 戻り値
+    /// エラーメッセージ
+    fn message(self, workspace_id: i64) -> String {
+        match self {
+            Self::AllProjectsFailed => {
+                format!("Failed to fetch issues for all projects of workspace {workspace_id}")
+            }
+            Self::AuthFailed => {
+                format!("Authentication failed. Disabled workspace {workspace_id}")
+            }
+            Self::UserChanged => {
+                format!("User of workspace {workspace_id} has changed. Confirm the change to sync")
+            }
+            Self::UserInfoUnavailable => {
+                format!("Failed to get user info for workspace {workspace_id}")
+            }
+        }
+    }
+}
+
+/// 1ワークスペース分の課題を取得・スコアリングしてDBへ保存する（手動同期の共通処理）
+///
+/// [`fetch_issues`] と [`fetch_single_workspace`] で共有する。`enabled` は判定しないため、
+/// 同期対象の選別は呼び出し元で行う。保存後は新規・更新課題の AI ジョブ投入と、
+/// コーパス・埋め込みのバックグラウンド処理の起動まで行う。
+/// 取得件数が上限に達したプロジェクトがあれば `fetch-truncated` イベントでフロントへ知らせる。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル（イベント送信用）
+/// * `db` - データベースクライアント
+/// * `workspace` - 同期するワークスペース
+/// * `fetch_period_days` - 課題取得対象の期間（日数。`None` で無制限）
+/// * `existing_updated_map` - 同期前のDBスナップショット `(workspace_id, issue_id) -> updated`
+///
+/// # 戻り値
+/// 保存した課題のベクタ。全プロジェクトの取得に失敗した場合やユーザー情報を取得できず
+/// スキップした場合、認証失敗でワークスペースを無効化した場合、APIキーのユーザーが保存済みの
+/// ユーザーと異なる場合は見送った理由（[`SyncSkipReason`]。前回の課題は残す）。
+/// 保存失敗時はエラーメッセージ
+async fn sync_workspace_issues(
+    app: &tauri::AppHandle,
+    db: &DbClient,
+    workspace: crate::db::Workspace,
+    fetch_period_days: Option<i64>,
+    existing_updated_map: &std::collections::HashMap<(i64, i64), Option<String>>,
+) -> Result<Result<Vec<crate::backlog::Issue>, SyncSkipReason>, String> {
+    let domain = workspace.domain;
+    let api_key = workspace.api_key;
+    let project_key = workspace.project_keys;
+
+    // Backlog APIクライアントを作成
+    let client = BacklogClient::new(&domain, &api_key).map_err(|e| e.to_string())?;
+
+    // プロジェクトキー（カンマ区切り）を分割して処理
+    let project_keys = crate::db::split_project_keys(&project_key);
+    let mut workspace_issues = Vec::new();
+    let mut synced_projects = Vec::new();
+    let mut auth_failed_projects = Vec::new();
+    let mut truncated_projects = Vec::new();
+    // 直近のレート残量（コーパス・コメント取得のバックオフ判定に流用。v0.4 / FR-V04-002）。
+    let mut last_remaining: Option<i64> = None;
+
+    // プロジェクトごとに課題を取得（レート残量に応じて並列数を調整）
+    let fetched = crate::scheduler::fetch_project_issues(
+        &client,
+        &project_keys,
+        fetch_period_days,
+        workspace.api_remaining,
+    )
+    .await;
+    for (key, result) in fetched {
+        match result {
+            Ok((issues, rate_limit)) => {
+                if crate::backlog::is_fetch_truncated(issues.len()) {
+                    truncated_projects.push(key.to_string());
+                }
+                workspace_issues.extend(issues);
+                synced_projects.push(key.to_string());
+                if rate_limit.remaining.is_some() {
+                    last_remaining = rate_limit.remaining;
+                }
+
+                // API使用状況を保存し、残量の推移をフロントへ知らせる
+                // 複数のプロジェクトを取得する場合はレスポンスごとに更新する
+                crate::scheduler::save_workspace_usage(app, db, workspace.id, rate_limit).await;
+            }
+            // 認証エラーは定期同期と同じく、全プロジェクトが失敗した場合に限って無効化する
+            Err(e) if crate::backlog::is_auth_error(&*e) => {
+                eprintln!("Authentication failed for project {key}: {e}");
+                auth_failed_projects.push((key.to_string(), e));
+            }
+            Err(e) => {
+                eprintln!("Failed to fetch issues for project {key}: {e}");
+                // エラーが発生しても他のプロジェクトの取得は継続
+            }
+        }
+    }
+    if let Some((_, e)) =
+        crate::scheduler::all_project_fetches_failed_with_auth(&project_keys, &auth_failed_projects)
+    {
+        disable_workspace_on_auth_failure(app, db, workspace.id, &domain, &**e).await;
+        return Ok(Err(SyncSkipReason::AuthFailed));
+    }
+    // 全プロジェクトの取得に失敗した場合は、前回の課題を残したまま同期を見送る
+    if crate::scheduler::all_project_fetches_failed(&project_keys, &synced_projects) {
+        eprintln!("Failed to fetch issues for all projects of {domain}. Keeping previous issues");
+        return Ok(Err(SyncSkipReason::AllProjectsFailed));
+    }
+    // 一部の課題しか取得できていない可能性をフロントへ知らせ、絞り込みの見直しを促す
+    if !truncated_projects.is_empty() {
+        use tauri::Emitter;
+        let truncated = crate::scheduler::FetchTruncated {
+            workspace_id: workspace.id,
+            domain: domain.clone(),
+            project_keys: truncated_projects,
+            limit: crate::backlog::ISSUES_FETCH_COUNT,
+        };
+        let _ = app.emit("fetch-truncated", &truncated);
+    }
+    let me = match client.get_myself().await {
+        Ok(me) => me,
+        // APIキーの無効（401）に限って無効化する（定期同期と同じ条件）
+        Err(e) if crate::backlog::is_authentication_error(&*e) => {
+            disable_workspace_on_auth_failure(app, db, workspace.id, &domain, &*e).await;
+            return Ok(Err(SyncSkipReason::AuthFailed));
+        }
+        Err(e) => {
+            eprintln!("Failed to get myself for {domain}: {e}");
+            return Ok(Err(SyncSkipReason::UserInfoUnavailable));
+        }
+    };
+
+    // 保存済みと別のユーザーなら、定期同期と同じく別人基準でスコアリング・保存しないよう
+    // 同期を見送り、`user-changed` イベントで確認を促す（accept_workspace_user_change で解消）
+    if let Some(change) = crate::scheduler::detect_user_change(
+        workspace.id,
+        &domain,
+        workspace.user_id,
+        workspace.user_name.as_deref(),
+        &me,
+    ) {
+        use tauri::Emitter;
+        eprintln!(
+            "User of {domain} changed from {} to {}. Skipping sync",
+            change.previous_user_id, change.current_user_id
+        );
+        let _ = app.emit("user-changed", &change);
+        return Ok(Err(SyncSkipReason::UserChanged));
+    }
+
+    // ユーザー情報を更新（まだ保存されていない場合のために）
+    if workspace.user_id.is_none() || workspace.user_name.is_none() {
+        let _ = db
+            .save_workspace(WorkspaceInput {
+                domain: domain.clone(),
+                api_key: api_key.clone(),
+                project_keys: project_key.clone(),
+                user_id: Some(me.id),
+                user_name: Some(me.name.clone()),
+                enabled: workspace.enabled,
+                api_limit: workspace.api_limit,
+                api_remaining: workspace.api_remaining,
+                api_reset: workspace.api_reset.clone(),
+                label: workspace.label.clone(),
+            })
+            .await;
+    }
+
+    // ウォッチ中の課題を取り込む（担当者でなくても把握したい課題。取得済みの課題とは重複しない）
+    crate::scheduler::merge_watching_issues(&client, me.id, &mut workspace_issues, &synced_projects)
+        .await;
+    // 自分がコメントした課題を判定する（関与している課題への加点用）
+    crate::scheduler::mark_commented_issues(
+        db,
+        &client,
+        workspace.id,
+        &me,
+        &mut workspace_issues,
+        existing_updated_map,
+        last_remaining,
+    )
+    .await;
+
+    // 各課題のスコアを計算（親課題加点モードなら子課題を持つ親課題へ加点）
+    let parent_issue_mode = crate::scheduler::resolve_parent_issue_mode(db).await;
+    let parent_ids = ScoringService::parent_issue_ids(&workspace_issues);
+    // 自分宛のお知らせ（取得できなければ説明文マッチにフォールバック）
+    let notified_ids = crate::scheduler::fetch_notified_issue_ids(&client).await;
+    let scoring_config = crate::scheduler::resolve_workspace_scoring_config(
+        db,
+        &crate::scheduler::resolve_scoring_config(db).await,
+        workspace.id,
+    )
+    .await;
+    let mut comment_checks = crate::scheduler::CommentCountChecks::load(
+        db,
+        workspace.id,
+        existing_updated_map,
+        last_remaining,
+    )
+    .await;
+    for issue in &mut workspace_issues {
+        issue.relevance_score = ScoringService::calculate_score_with_notifications(
+            issue,
+            &me,
+            notified_ids.as_ref(),
+            &scoring_config,
+        );
+        if parent_issue_mode == ParentIssueMode::ParentBonus {
+            issue.relevance_score += ScoringService::parent_bonus(issue, &parent_ids);
+        }
+        let score = issue.relevance_score;
+        issue.relevance_score +=
+            crate::scheduler::fetch_comment_bonus(&client, &mut comment_checks, issue, score)
+                .await;
+        issue.workspace_id = workspace.id;
+    }
+
+    // データベースに保存
+    // 設定によりスコア0の課題は保存しない（取得できた課題なので削除対象にはしない）
+    let retained_ids = if db.saves_zero_score_issues().await.unwrap_or(true) {
+        Vec::new()
+    } else {
+        crate::scheduler::exclude_zero_score_issues(&mut workspace_issues)
+    };
+    // Vec<String> を Vec<&str> に変換
+    let synced_projects_refs: Vec<&str> = synced_projects.iter().map(|s| s.as_str()).collect();
+
+    db.save_issues_retaining(
+        workspace.id,
+        &workspace_issues,
+        &retained_ids,
+        &synced_projects_refs,
+        &project_keys,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    // 課題一覧に「最終更新」を表示するため、保存できた時刻を記録する
+    if let Err(e) = db.record_workspace_synced(workspace.id, chrono::Utc::now()).await {
+        eprintln!("Failed to record last synced time of {domain}: {e}");
+    }
+
+    // 保存成功後、新規・更新チケットをAIジョブとしてキュー投入する（FR-V03-004 / 手動sync経路）。
+    // 同期対象は呼び出し元で決定済み（fetch_issues は有効なワークスペースのみ、
+    // fetch_single_workspace は enabled に関わらず明示指定されたワークスペース）。
+    // 差分検出ロジックは scheduler 経路と共通化している。
+    crate::scheduler::enqueue_changed_issues(
+        db,
+        workspace.id,
+        &workspace_issues,
+        existing_updated_map,
+    )
+    .await;
+
+    // v0.4: 完了課題コーパス取り込み・コメント差分取得・埋め込みジョブ投入（手動sync経路）。
+    // これらは API 直列取得（コーパス最大 MAX_CORPUS_PAGES × プロジェクト + コメント最大 N 件）を
+    // 含み、初回ビルド時は重い。通常 sync・スコアリング・保存はこの時点で完了済みのため、
+    // この重い部分は**バックグラウンドタスクへ逃がして**呼び出し元（手動同期コマンド）を即返す
+    // （NFR-V04-002 / NFR-V04-005: sync・UI を阻害しない）。必要データを owned へクローンして move する。
+    {
+        let db_bg = db.clone();
+        let client_bg = client.clone();
+        let ws_id = workspace.id;
+        let project_keys_bg: Vec<String> = project_keys.iter().map(|s| s.to_string()).collect();
+        let issues_bg = workspace_issues.clone();
+        let updated_map_bg = existing_updated_map.clone();
+        let rate_remaining = last_remaining;
+        tauri::async_runtime::spawn(async move {
+            let pk_refs: Vec<&str> = project_keys_bg.iter().map(|s| s.as_str()).collect();
+            crate::scheduler::sync_corpus_and_embeddings(
+                &db_bg,
+                &client_bg,
+                ws_id,
+                &pk_refs,
+                &issues_bg,
+                &updated_map_bg,
+                rate_remaining,
+            )
+            .await;
+        });
+    }
+
+    Ok(Ok(workspace_issues))
+}
+
+/// 手動同期の結果（[`fetch_issues`] の戻り値）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncResult {
+    /// 全ワークスペースで取得した課題の合計件数
+    pub total: usize,
+    /// ワークスペースごとの結果（同期対象の有効なワークスペースのみ）
+    pub per_workspace: Vec<WorkspaceSyncResult>,
+}
+
+impl crate::scheduler::SyncedCount for SyncResult {
+    fn synced_count(&self) -> usize {
+        self.total
+    }
+}
+
+/// 1ワークスペース分の同期結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceSyncResult {
+    /// ワークスペースID
+    pub workspace_id: i64,
+    /// 取得した課題の件数（失敗時は 0）
+    pub count: usize,
+    /// 失敗した場合のエラーメッセージ（成功時は `None`）
+    pub error: Option<String>,
+}
+
+/// Backlogから課題を取得してスコアリング
+///
+/// 以下の処理を実行する：
+/// 1. データベースから設定（ドメイン、APIキー、プロジェクトキー）を取得
+/// 2. Backlog APIから課題一覧を取得
+/// 3. 現在のユーザー情報を取得
+/// 4. 各課題の関連度スコアを計算
+/// 5. 課題をデータベースに保存
+///
+/// 同期の開始・終了時に `sync-started` / `sync-finished` イベントを、各ワークスペースの
+/// 開始・終了時に `sync-progress` イベント（[`crate::scheduler::SyncProgress`]）を発火する。
+/// 一部のワークスペースで失敗しても他のワークスペースの同期は継続し、失敗は
+/// [`WorkspaceSyncResult::error`] で返す。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル（トレイ更新・イベント発火用）
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+///
+/// # 戻り値
+/// 合計件数とワークスペースごとの結果、またはエラーメッセージ
+#[tauri::command]
+pub async fn fetch_issues(
+    app: tauri::AppHandle,
+    db: State<'_, DbClient>,
+) -> Result<SyncResult, String> {
+    crate::scheduler::with_sync_events(
+        &app,
+        crate::scheduler::SyncTrigger::Manual,
+        sync_all_workspaces(&app, &db),
+    )
+    .await
+}
+
+/// 手動同期（[`sync_all_workspaces`]）でのワークスペースの扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ManualSyncAction {
+    /// 同期する
+    Sync,
+    /// 同期せず、課題も残す
+    Skip,
+    /// 同期せず、課題を削除する
+    DeleteIssues,
+}
+
+/// 手動同期でのワークスペースの扱いを決める
+///
+/// 無効なワークスペースは同期せず、課題を削除する（保持する設定なら残す）。スヌーズ中の
+/// ワークスペースは定期同期（[`crate::scheduler::is_scheduled_sync_target`]）と同じく同期せず、
+/// 課題は残す。
+///
+/// # 引数
+/// * `workspace` - 判定するワークスペース
+/// * `keep_disabled_issues` - 無効なワークスペースの課題を保持するなら `true`
+/// * `now` - スヌーズ期限の判定の基準時刻
+///
+/// # 戻り値
+/// ワークスペースの扱い
+fn manual_sync_action(
+    workspace: &crate::db::Workspace,
+    keep_disabled_issues: bool,
+    now: chrono::DateTime<chrono::Utc>,
+) -> ManualSyncAction {
+    if crate::scheduler::is_scheduled_sync_target(workspace, now) {
+        ManualSyncAction::Sync
+    } else if workspace.enabled || keep_disabled_issues {
+        ManualSyncAction::Skip
+    } else {
+        ManualSyncAction::DeleteIssues
+    }
+}
+
+/// 全ワークスペースを同期してトレイ表示を更新する（[`fetch_issues`] の本体）
+async fn sync_all_workspaces(
+    app: &tauri::AppHandle,
+    db: &DbClient,
+) -> Result<SyncResult, String> {
+    let workspaces = db.get_workspaces().await.map_err(|e| e.to_string())?;
+    let mut total_count = 0;
+    let mut per_workspace = Vec::new();
+    let mut all_issues_for_tooltip = Vec::new();
+
+    // 同期前のDBスナップショット（最終更新日時）を取得し、AIジョブ投入の差分検出に流用する。
+    // 差分検出に必要なのは更新日時だけなので、JSON デシリアライズ・ai_results JOIN を伴う
+    // get_issues ではなく軽量な get_issue_updated_map を使う（課題が多くても同期を遅くしない）。
+    let existing_updated_map = db
+        .get_issue_updated_map()
+        .await
+        .map_err(|e| e.to_string())?;
+    // 同期の前後で比較し、追加・更新・削除された課題をフロントへ知らせる
+    let issue_snapshot = db.get_issue_snapshot().await.map_err(|e| e.to_string())?;
+
+    // 課題取得対象の期間（日数。未設定なら無制限）
+    let fetch_period_days = crate::scheduler::resolve_fetch_period_days(db).await;
+
+    // 無効ワークスペースの課題を保持するか（既定は削除）
+    let keep_disabled_issues = db.keeps_disabled_workspace_issues().await.unwrap_or(false);
+    // レート残量が少ないワークスペースの同期を見送る基準
+    let rate_limit_skip_threshold = crate::scheduler::resolve_rate_limit_skip_threshold(db).await;
+    let now = chrono::Utc::now();
+
+    let mut enabled_workspaces = Vec::new();
+    for workspace in workspaces {
+        match manual_sync_action(&workspace, keep_disabled_issues, now) {
+            ManualSyncAction::Sync => {}
+            ManualSyncAction::Skip => continue,
+            ManualSyncAction::DeleteIssues => {
+                if let Err(e) = db.delete_workspace_issues(workspace.id).await {
+                    eprintln!(
+                        "Failed to delete issues for disabled workspace {}: {}",
+                        workspace.id, e
+                    );
+                }
+                continue;
+            }
+        }
+        // レート残量を使い切らないよう、残量が少なければリセット時刻まで同期を見送る
+        if let Some(rate_limited) = crate::scheduler::detect_rate_limited(
+            workspace.id,
+            &workspace.domain,
+            workspace.api_remaining,
+            workspace.api_reset.as_deref(),
+            rate_limit_skip_threshold,
+            now,
+        ) {
+            use tauri::Emitter;
+            log::info!(
+                "Skipping sync for {}: rate limit remaining {} until {}",
+                rate_limited.domain,
+                rate_limited.remaining,
+                rate_limited.reset
+            );
+            per_workspace.push(WorkspaceSyncResult {
+                workspace_id: workspace.id,
+                count: 0,
+                error: Some(format!("Rate limited until {}", rate_limited.reset)),
+            });
+            let _ = app.emit("rate-limited", &rate_limited);
+            continue;
+        }
+        enabled_workspaces.push(workspace);
+    }
+
+    // ワークスペース単位の同期は独立しているため、同時実行数の上限付きで並列に行う。
+    // 保存は save_issues のワークスペースごとのトランザクションで分離される。
+    // buffered は入力順に結果を返すため、ID 列と突き合わせて結果を対応付ける。
+    // 1つのワークスペースが遅くても他を処理できるよう、ワークスペース単位でも打ち切る。
+    // タイムアウトしたワークスペースは、保存をコミットする前なら取得途中の課題を破棄して
+    // 前回の同期結果を残す（保存は1トランザクションのため、一部だけ保存されることはない）。
+    let workspace_timeout =
+        crate::scheduler::workspace_sync_timeout(crate::scheduler::resolve_sync_timeout(db).await);
+    // 各ワークスペースの開始・終了時に `sync-progress` を発火し、終わった順に数え上げる
+    let workspace_ids: Vec<i64> = enabled_workspaces.iter().map(|w| w.id).collect();
+    let progress = crate::scheduler::SyncProgressTracker::new(enabled_workspaces.len());
+    let progress = &progress;
+    let existing_updated_map = &existing_updated_map;
+    let results: Vec<_> = futures::stream::iter(enabled_workspaces)
+        .map(|workspace| async move {
+            use tauri::Emitter;
+            let label = workspace.display_name.clone();
+            let _ = app.emit(crate::scheduler::SYNC_PROGRESS_EVENT, progress.started(&label));
+            let result = crate::scheduler::with_timeout(
+                workspace_timeout,
+                "Workspace sync",
+                sync_workspace_issues(app, db, workspace, fetch_period_days, existing_updated_map),
+            )
+            .await;
+            let _ = app.emit(crate::scheduler::SYNC_PROGRESS_EVENT, progress.finished(&label));
+            result
+        })
+        .buffered(crate::scheduler::MAX_CONCURRENT_WORKSPACE_SYNCS)
+        .collect()
+        .await;
+    for (workspace_id, result) in workspace_ids.into_iter().zip(results) {
+        let result = result
+            .and_then(|issues| issues.map_err(|reason| reason.message(workspace_id)));
+        match result {
+            Ok(mut workspace_issues) => {
+                per_workspace.push(WorkspaceSyncResult {
+                    workspace_id,
+                    count: workspace_issues.len(),
+                    error: None,
+                });
+                total_count += workspace_issues.len();
+                all_issues_for_tooltip.append(&mut workspace_issues);
+            }
+            Err(e) => {
+                eprintln!("Failed to sync workspace {workspace_id}: {e}");
+                per_workspace.push(WorkspaceSyncResult {
+                    workspace_id,
+                    count: 0,
+                    error: Some(e),
+                });
+            }
+        }
+    }
+
+    // トレイのツールチップを更新
+    let thresholds = crate::scheduler::resolve_notification_thresholds(db).await;
+    let high_priority_count =
+        crate::scheduler::count_high_priority(&all_issues_for_tooltip, &thresholds);
+
+    // 言語設定を取得（デフォルトは日本語）
+    let lang = db
+        .get_setting("language")
+        .await
+        .unwrap_or(Some("ja".to_string()))
+        .unwrap_or("ja".to_string());
+
+    crate::scheduler::update_tray_tooltip(app, &lang, high_priority_count);
+    crate::scheduler::emit_issues_delta(app, db, &issue_snapshot).await;
+
+    Ok(SyncResult {
+        total: total_count,
+        per_workspace,
+    })
+}
+
+/// スケジューラーと同じ同期・通知処理を今すぐ実行
+///
+/// 定期同期と同じく高スコア課題の通知まで行う。設定 `auto_sync_enabled` で定期同期を
+/// 無効にしていても実行できる。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+///
+/// # 戻り値
+/// 処理した課題の件数、またはエラーメッセージ
+#[tauri::command]
+pub async fn trigger_sync(app: tauri::AppHandle) -> Result<usize, String> {
+    crate::scheduler::run_sync(&app, crate::scheduler::SyncTrigger::Manual).await
+}
+
+/// 定期同期のスケジューラーを止める
+///
+/// 実行中の同期は最後まで終わらせる。[`start_scheduler`] で再開できる。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+///
+/// # 戻り値
+/// 動いていたスケジューラーを止めた場合は `true`
+#[tauri::command]
+pub async fn stop_scheduler(app: tauri::AppHandle) -> Result<bool, String> {
+    Ok(crate::scheduler::stop(&app))
+}
+
+/// 定期同期のスケジューラーを起動する
+///
+/// 起動直後に1回同期する。既に動いている場合は二重に起動しない。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+///
+/// # 戻り値
+/// 新たに起動した場合は `true`（既に動いていれば `false`）
+#[tauri::command]
+pub async fn start_scheduler(app: tauri::AppHandle) -> Result<bool, String> {
+    Ok(crate::scheduler::start(&app, true))
+}
+
+/// 指定したワークスペースだけを手動同期
+///
+/// `enabled` フラグに関わらず指定ワークスペースの課題を取得・スコアリングして保存する。
+/// 無効ワークスペースを一時的に確認する用途を想定し、[`fetch_issues`] の
+/// 「無効ワークスペースの課題削除」は適用しない。同期後に `refresh-issues` イベントを発火する。
+/// [`fetch_issues`] と同じく `sync-started` / `sync-finished` イベントも発火する。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル（イベント発火用）
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `workspace_id` - 同期するワークスペースID
+///
+/// # 戻り値
+/// 取得した課題の件数、またはエラーメッセージ
+#[tauri::command]
+pub async fn fetch_single_workspace(
+    app: tauri::AppHandle,
+    db: State<'_, DbClient>,
+    workspace_id: i64,
+) -> Result<usize, String> {
+    crate::scheduler::with_sync_events(
+        &app,
+        crate::scheduler::SyncTrigger::Manual,
+        sync_single_workspace(&app, &db, workspace_id),
+    )
+    .await
+}
+
+/// 指定ワークスペースを同期して `refresh-issues` を発火する（[`fetch_single_workspace`] の本体）
+async fn sync_single_workspace(
+    app: &tauri::AppHandle,
+    db: &DbClient,
+    workspace_id: i64,
+) -> Result<usize, String> {
+    use tauri::Emitter;
+
+    let workspace = db
+        .get_workspaces()
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|w| w.id == workspace_id)
+        .ok_or_else(|| format!("Workspace not found: {workspace_id}"))?;
+
+    let existing_updated_map = db
+        .get_issue_updated_map()
+        .await
+        .map_err(|e| e.to_string())?;
+    let issue_snapshot = db.get_issue_snapshot().await.map_err(|e| e.to_string())?;
+    let fetch_period_days = crate::scheduler::resolve_fetch_period_days(db).await;
+
+    let count = sync_workspace_issues(app, db, workspace, fetch_period_days, &existing_updated_map)
+        .await?
+        .map(|issues| issues.len())
+        .map_err(|reason| reason.message(workspace_id))?;
+
+    // フロントエンドに更新通知を送る（現在時刻を付与。scheduler と同じ形式）
+    let now = chrono::Local::now().format("%H:%M").to_string();
+    let _ = app.emit("refresh-issues", now);
+    crate::scheduler::emit_issues_delta(app, db, &issue_snapshot).await;
+
+    Ok(count)
+}
+
+/// 課題のスコア内訳を取得
+///
+/// 保存済みの課題とワークスペースのユーザー情報から、加点要素ごとの内訳を再計算して返す。
+/// 同期時と同じく、ワークスペースごとのスコアリング設定と自分宛のお知らせ（メンション判定）、
+/// 保存済みのコメント数・コメントでのメンションを使う。
+/// 期限・更新の判定は現在時刻で行うため、同期時に保存したスコアと異なる場合がある。
+/// 親課題加点モードのときは、同じワークスペースの保存済み課題から親子関係を判定する。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `workspace_id` - 課題が属するワークスペースID
+/// * `issue_id` - 課題ID
+///
+/// # 戻り値
+/// スコアの内訳、またはエラーメッセージ
+#[tauri::command]
+pub async fn explain_issue_score(
+    db: State<'_, DbClient>,
+    workspace_id: i64,
+    issue_id: i64,
+) -> Result<crate::scoring::ScoreBreakdown, String> {
+    let workspace = db
+        .get_workspaces()
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|w| w.id == workspace_id)
+        .ok_or_else(|| format!("Workspace not found: {workspace_id}"))?;
+    let (Some(user_id), Some(user_name)) = (workspace.user_id, workspace.user_name) else {
+        return Err(format!("User info is not available for workspace {workspace_id}"));
+    };
+    let me = crate::backlog::User {
+        id: user_id,
+        name: user_name,
+        mail_address: None,
+        role_type: None,
+    };
+
+    let issues: Vec<crate::backlog::Issue> = db
+        .get_issues()
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|i| i.workspace_id == workspace_id)
+        .collect();
+    let issue = issues
+        .iter()
+        .find(|i| i.id == issue_id)
+        .ok_or_else(|| format!("Issue not found: {issue_id}"))?;
+
+    let scoring_config = crate::scheduler::resolve_workspace_scoring_config(
+        &db,
+        &crate::scheduler::resolve_scoring_config(&db).await,
+        workspace_id,
+    )
+    .await;
+    // 同期時と同じく自分宛のお知らせでメンションを判定する（取得できなければ説明文マッチ）
+    let client =
+        BacklogClient::new(&workspace.domain, &workspace.api_key).map_err(|e| e.to_string())?;
+    let notified_ids = crate::scheduler::fetch_notified_issue_ids(&client).await;
+    let mut breakdown = ScoringService::calculate_score_breakdown_with_notifications(
+        issue,
+        &me,
+        notified_ids.as_ref(),
+        &scoring_config,
+    );
+    if crate::scheduler::resolve_parent_issue_mode(&db).await == ParentIssueMode::ParentBonus {
+        let parent_ids = ScoringService::parent_issue_ids(&issues);
+        breakdown.parent = ScoringService::parent_bonus(issue, &parent_ids);
+    }
+    Ok(breakdown)
+}
+
+/// データベースをバックアップ
+///
+/// `dest_path` がディレクトリ（既存、または末尾がパス区切り）ならタイムスタンプ付きの既定名で、
+/// それ以外はそのファイル名でバックアップを作成する。保存先ディレクトリが無ければ作成する。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `dest_path` - 保存先のディレクトリまたはファイルパス
+///
+/// # 戻り値
+/// 作成したバックアップファイルのパス、またはエラーメッセージ
+#[tauri::command]
+pub async fn backup_database(
+    db: State<'_, DbClient>,
+    dest_path: String,
+) -> Result<String, String> {
+    let path = crate::db::resolve_backup_path(&dest_path, chrono::Local::now());
+    db.backup_to(&path).await.map_err(|e| e.to_string())?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// 課題数の統計を取得
+///
+/// 総数・高優先度（80点以上）・期限超過の件数と、ワークスペース別の件数を返す。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+///
+/// # 戻り値
+/// 課題数の統計、またはエラーメッセージ
+#[tauri::command]
+pub async fn get_issue_stats(db: State<'_, DbClient>) -> Result<crate::db::IssueStats, String> {
+    db.get_issue_stats().await.map_err(|e| e.to_string())
+}
+
+/// 保存済みの課題一覧をエクスポート
+///
+/// 会議資料・レポート用に、課題キー・件名・ステータス・担当者・期限日・スコアを整形して返す。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `format` - 出力形式（`csv` / `markdown`）
+///
+/// # 戻り値
+/// 整形した文字列（UTF-8）、またはエラーメッセージ
+#[tauri::command]
+pub async fn export_issues(db: State<'_, DbClient>, format: String) -> Result<String, String> {
+    let format = crate::export::ExportFormat::parse(&format)
+        .ok_or_else(|| format!("Unsupported export format: {format}"))?;
+    let issues = db.get_issues().await.map_err(|e| e.to_string())?;
+    Ok(crate::export::export_issues(&issues, format))
+}
+
+/// Backlogスペースの情報を取得するコマンド
+///
+/// 設定画面でスペースの正式名称などを表示するために使用する。表示用の補助情報のため、
+/// 取得に失敗してもワークスペースの保存は妨げない（呼び出し側でエラーを無視してよい）。
+///
+/// # 引数
+/// * `domain` - Backlogドメイン
+/// * `api_key` - APIキー
+///
+/// # 戻り値
+/// スペース情報、またはエラーメッセージ
+#[tauri::command]
+pub async fn get_space_info(
+    domain: String,
+    api_key: String,
+) -> Result<crate::backlog::Space, String> {
+    let domain = crate::backlog::normalize_domain(&domain)?;
+    let client = BacklogClient::new(&domain, &api_key).map_err(|e| e.to_string())?;
+    client.get_space().await.map_err(|e| e.to_string())
+}
+
+/// プロジェクト一覧を取得するコマンド
+///
+/// Backlog APIから自分がアクセス可能なプロジェクト一覧を取得する。
+/// 設定画面でプロジェクトを選択する際に使用。
+///
+/// # 戻り値
+/// プロジェクト情報のベクタ（プロジェクトキーと名前）
+#[tauri::command]
+pub async fn fetch_projects(
+    domain: String,
+    api_key: String,
+) -> Result<Vec<(String, String)>, String> {
+    // Backlog APIクライアントを作成（ドメインは正規化してから使う）
+    let domain = crate::backlog::normalize_domain(&domain)?;
+    let client = BacklogClient::new(&domain, &api_key).map_err(|e| e.to_string())?;
+
+    // プロジェクト一覧を取得
+    let projects = client.get_projects().await.map_err(|e| e.to_string())?;
+
+    // (project_key, name) のタプルに変換
+    let result: Vec<(String, String)> = projects
+        .iter()
+        .map(|p| (p.project_key.clone(), p.name.clone()))
+        .collect();
+
+    Ok(result)
+}
+
+/// 保存された課題一覧を取得
+///
+/// データベースに保存されている課題を関連度スコアの降順で取得する。
+/// 各課題には所属ワークスペースのドメイン・ラベルを付与し、どのBacklogスペースの課題かを
+/// フロントで `get_workspaces` と突き合わせずに表示できるようにする。
+/// 設定 `parent_issue_mode` が `children_only` の場合は、子課題（親課題IDを持つ課題）のみを返す。
+/// `categories` を指定した場合は、いずれかのカテゴリー名が付いた課題のみを返す。
+/// `issue_types` を指定した場合は、いずれかの種別名の課題のみを返す。
+/// `sort_by` で並び替えの基準を選べる（未指定はスコア順）。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `categories` - 絞り込むカテゴリー名（未指定・空なら絞り込まない）
+/// * `issue_types` - 絞り込む種別名（未指定・空なら絞り込まない）
+/// * `sort_by` - 並び替えの基準（未指定ならスコア）
+/// * `descending` - 降順なら `true`（未指定なら基準ごとの既定。[`SortKey::default_descending`]）
+///
+/// # 戻り値
+/// ワークスペース情報付き課題のリスト（指定の順）と最終同期時刻、またはエラーメッセージ
+#[tauri::command]
+pub async fn get_issues(
+    db: State<'_, DbClient>,
+    categories: Option<Vec<String>>,
+    issue_types: Option<Vec<String>>,
+    sort_by: Option<SortKey>,
+    descending: Option<bool>,
+) -> Result<IssuesResponse, String> {
+    let sort_by = sort_by.unwrap_or_default();
+    let descending = descending.unwrap_or_else(|| sort_by.default_descending());
+    let mut issues = db
+        .get_issues_with_workspace_sorted(sort_by, descending)
+        .await
+        .map_err(|e| e.to_string())?;
+    if crate::scheduler::resolve_parent_issue_mode(&db).await == ParentIssueMode::ChildrenOnly {
+        issues.retain(|row| row.issue.parent_issue_id.is_some());
+    }
+    if let Some(categories) = categories {
+        issues.retain(|row| row.issue.has_any_category(&categories));
+    }
+    if let Some(issue_types) = issue_types {
+        issues.retain(|row| row.issue.has_any_issue_type(&issue_types));
+    }
+    let workspaces_last_synced =
+        db.get_workspaces_last_synced().await.map_err(|e| e.to_string())?;
+    Ok(IssuesResponse::new(issues, workspaces_last_synced))
+}
+
+/// 課題一覧（[`get_issues`] の戻り値）
+///
+/// 表示中の課題がいつ時点のものか示せるよう、最終同期時刻を添える。
+/// 時刻は RFC3339 形式で、一度も同期していなければ `None`（UI では「未同期」と表示する）。
+#[derive(Debug, Clone, Serialize)]
+pub struct IssuesResponse {
+    /// ワークスペース情報付き課題のリスト
+    pub issues: Vec<crate::db::IssueWithWorkspace>,
+    /// 全ワークスペースのうち最も新しい同期時刻
+    pub last_synced_at: Option<String>,
+    /// ワークスペースIDごとの最終同期時刻（一度も同期していないワークスペースは含まない）
+    pub workspaces_last_synced: std::collections::HashMap<i64, String>,
+}
+
+impl IssuesResponse {
+    /// ワークスペースごとの最終同期時刻から全体の最終同期時刻を求めて組み立てる
+    ///
+    /// # 引数
+    /// * `issues` - 課題のリスト
+    /// * `workspaces_last_synced` - ワークスペースIDごとの最終同期時刻（RFC3339）
+    fn new(
+        issues: Vec<crate::db::IssueWithWorkspace>,
+        workspaces_last_synced: std::collections::HashMap<i64, String>,
+    ) -> Self {
+        let last_synced_at = workspaces_last_synced
+            .values()
+            .filter_map(|at| chrono::DateTime::parse_from_rfc3339(at).ok())
+            .max()
+            .map(|at| at.to_rfc3339());
+        Self {
+            issues,
+            last_synced_at,
+            workspaces_last_synced,
+        }
+    }
+}
+
+/// 課題一覧のグループ化の単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IssueGroupBy {
+    /// ワークスペースごと
+    Workspace,
+    /// ステータスごと
+    Status,
+}
+
+impl IssueGroupBy {
+    /// コマンド引数の文字列（`workspace` / `status`）から変換する
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "workspace" => Ok(Self::Workspace),
+            "status" => Ok(Self::Status),
+            other => Err(format!("Unknown group_by: {}", other)),
+        }
+    }
+}
+
+/// グループ化した課題一覧の1グループ
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueGroup {
+    /// グループ名（ワークスペースの表示名、またはステータス名）。ステータス未設定の課題は None
+    pub name: Option<String>,
+    /// グループ内の課題のスコア合計
+    pub total_score: i64,
+    /// グループ内の課題（スコアの降順）
+    pub issues: Vec<crate::db::IssueWithWorkspace>,
+}
+
+/// 課題をグループ化する
+///
+/// グループ内はスコアの降順、グループはスコア合計の降順（同点なら件数の多い順）に並べる。
+/// 課題が無いグループは作られない。
+///
+/// # 引数
+/// * `issues` - グループ化する課題
+/// * `group_by` - グループ化の単位
+///
+/// # 戻り値
+/// 並べ替え済みのグループのリスト
+fn group_issues(
+    issues: Vec<crate::db::IssueWithWorkspace>,
+    group_by: IssueGroupBy,
+) -> Vec<IssueGroup> {
+    let mut groups: Vec<(Option<String>, IssueGroup)> = Vec::new();
+    for row in issues {
+        let (key, name) = match group_by {
+            IssueGroupBy::Workspace => {
+                let name = row
+                    .workspace_label
+                    .clone()
+                    .filter(|label| !label.is_empty())
+                    .unwrap_or_else(|| row.workspace_domain.clone());
+                (Some(row.issue.workspace_id.to_string()), Some(name))
+            }
+            IssueGroupBy::Status => {
+                let name = row.issue.status.as_ref().map(|status| status.name.clone());
+                (name.clone(), name)
+            }
+        };
+        let index = match groups.iter().position(|(candidate, _)| *candidate == key) {
+            Some(index) => index,
+            None => {
+                let group = IssueGroup {
+                    name,
+                    total_score: 0,
+                    issues: Vec::new(),
+                };
+                groups.push((key, group));
+                groups.len() - 1
+            }
+        };
+        let group = &mut groups[index].1;
+        group.total_score += i64::from(row.issue.relevance_score);
+        group.issues.push(row);
+    }
+
+    let mut groups: Vec<IssueGroup> = groups.into_iter().map(|(_, group)| group).collect();
+    for group in &mut groups {
+        group
+            .issues
+            .sort_by(|a, b| b.issue.relevance_score.cmp(&a.issue.relevance_score));
+    }
+    groups.sort_by(|a, b| {
+        b.total_score
+            .cmp(&a.total_score)
+            .then_with(|| b.issues.len().cmp(&a.issues.len()))
+    });
+    groups
+}
+
+/// グループ化した課題一覧を取得
+///
+/// 保存済みの課題をワークスペース別またはステータス別にまとめて返す。
+/// 設定 `parent_issue_mode` による絞り込みは [`get_issues`] と同じ。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `group_by` - グループ化の単位（`workspace` / `status`）
+///
+/// # 戻り値
+/// スコア合計の降順に並べたグループのリスト、またはエラーメッセージ
+#[tauri::command]
+pub async fn get_issues_grouped(
+    db: State<'_, DbClient>,
+    group_by: String,
+) -> Result<Vec<IssueGroup>, String> {
+    let group_by = IssueGroupBy::parse(&group_by)?;
+    let mut issues = db
+        .get_issues_with_workspace()
+        .await
+        .map_err(|e| e.to_string())?;
+    if crate::scheduler::resolve_parent_issue_mode(&db).await == ParentIssueMode::ChildrenOnly {
+        issues.retain(|row| row.issue.parent_issue_id.is_some());
+    }
+    Ok(group_issues(issues, group_by))
+}
+
+/// 条件で絞り込んだ課題一覧を取得
 ///
-/// 指定されたキーの設定値をデータベースから取得する。
+/// 絞り込みは [`DbClient::get_issues_filtered`] で行う。並び順はスコアの降順。
+/// [`get_issues`] と同じく、親子課題の扱いが `children_only` なら子課題だけを返す。
+/// `filter_id` を指定すると保存済みのフィルタ（[`save_filter`]）を使い、個別に指定した条件で
+/// その項目だけを上書きする。
 ///
 /// # 引数
-/// * `key` - 設定のキー
 /// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `status` - ステータス名（未指定なら絞り込まない）
+/// * `priority` - 優先度名（未指定なら絞り込まない）
+/// * `assignee` - 担当者名（未指定なら絞り込まない）
+/// * `min_score` - スコアの下限（未指定なら絞り込まない）
+/// * `workspace_id` - ワークスペースID（未指定なら絞り込まない）
+/// * `filter_id` - 適用する保存済みフィルタのID
 ///
 /// # 戻り値
-/// 設定値（存在しない場合は`None`）、またはエラーメッセージ
+/// 条件に一致する課題のリスト、またはエラーメッセージ
 #[tauri::command]
-pub async fn get_settings(key: String, db: State<'_, DbClient>) -> Result<Option<String>, String> {
-    db.get_setting(&key).await.map_err(|e| e.to_string())
+pub async fn get_filtered_issues(
+    db: State<'_, DbClient>,
+    status: Option<String>,
+    priority: Option<String>,
+    assignee: Option<String>,
+    min_score: Option<i32>,
+    workspace_id: Option<i64>,
+    filter_id: Option<i64>,
+) -> Result<Vec<crate::backlog::Issue>, String> {
+    let base = match filter_id {
+        Some(id) => db
+            .get_filter(id)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Filter not found: {id}"))?,
+        None => crate::db::IssueFilter::default(),
+    };
+    let filter = crate::db::IssueFilter {
+        status: status.or(base.status),
+        priority: priority.or(base.priority),
+        assignee: assignee.or(base.assignee),
+        min_score: min_score.or(base.min_score),
+        workspace_id: workspace_id.or(base.workspace_id),
+    };
+    let mut issues = db
+        .get_issues_filtered(&filter)
+        .await
+        .map_err(|e| e.to_string())?;
+    if crate::scheduler::resolve_parent_issue_mode(&db).await == ParentIssueMode::ChildrenOnly {
+        issues.retain(|issue| issue.parent_issue_id.is_some());
+    }
+    Ok(issues)
 }
 
-/// Backlogから課題を取得してスコアリング
+/// 絞り込み条件を名前を付けて保存
 ///
-/// 以下の処理を実行する：
-/// 1. データベースから設定（ドメイン、APIキー、プロジェクトキー）を取得
-/// 2. Backlog APIから課題一覧を取得
-/// 3. 現在のユーザー情報を取得
-/// 4. 各課題の関連度スコアを計算
-/// 5. 課題をデータベースに保存
+/// 同じ名前のフィルタがあれば上書きする。
 ///
 /// # 引数
 /// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `name` - フィルタ名
+/// * `filter_json` - 絞り込み条件（[`crate::db::IssueFilter`] の JSON）
 ///
 /// # 戻り値
-/// 取得した課題の件数、またはエラーメッセージ
+/// 保存したフィルタのID。JSON が不正・名前が空の場合はエラーメッセージ
 #[tauri::command]
-pub async fn fetch_issues(app: tauri::AppHandle, db: State<'_, DbClient>) -> Result<usize, String> {
-    let workspaces = db.get_workspaces().await.map_err(|e| e.to_string())?;
-    let mut total_count = 0;
-    let mut all_issues_for_tooltip = Vec::new();
-
-    // 同期前のDBスナップショット（最終更新日時）を取得し、AIジョブ投入の差分検出に流用する。
-    // 差分検出に必要なのは更新日時だけなので、JSON デシリアライズ・ai_results JOIN を伴う
-    // get_issues ではなく軽量な get_issue_updated_map を使う（課題が多くても同期を遅くしない）。
-    let existing_updated_map = db
-        .get_issue_updated_map()
+pub async fn save_filter(
+    db: State<'_, DbClient>,
+    name: String,
+    filter_json: String,
+) -> Result<i64, String> {
+    let filter: crate::db::IssueFilter =
+        serde_json::from_str(&filter_json).map_err(|e| format!("Invalid filter JSON: {e}"))?;
+    db.save_filter(&name, &filter)
         .await
-        .map_err(|e| e.to_string())?;
-
-    for workspace in workspaces {
-        // 無効なワークスペースはスキップし、関連する課題を削除
-        if !workspace.enabled {
-            if let Err(e) = db.delete_workspace_issues(workspace.id).await {
-                eprintln!(
-                    "Failed to delete issues for disabled workspace {}: {}",
-                    workspace.id, e
-                );
-            }
-            continue;
-        }
-
-        let domain = workspace.domain;
-        let api_key = workspace.api_key;
-        let project_key = workspace.project_keys;
-
-        // Backlog APIクライアントを作成
-        let client = BacklogClient::new(&domain, &api_key);
-
-        // 取得対象のステータスID（未対応:1, 処理中:2, 処理済み:3）
-        let target_status_ids = vec![1, 2, 3];
-
-        // プロジェクトキー（カンマ区切り）を分割して処理
-        let project_keys: Vec<&str> = project_key
-            .split(',')
-            .map(|k| k.trim())
-            .filter(|k| !k.is_empty())
-            .collect();
-        let mut workspace_issues = Vec::new();
-        let mut synced_projects = Vec::new();
-        // 直近のレート残量（コーパス・コメント取得のバックオフ判定に流用。v0.4 / FR-V04-002）。
-        let mut last_remaining: Option<i64> = None;
-
-        for &key in &project_keys {
-            // プロジェクトごとに課題を取得
-            match client.get_issues(key, &target_status_ids).await {
-                Ok((issues, rate_limit)) => {
-                    workspace_issues.extend(issues);
-                    synced_projects.push(key.to_string());
-                    if rate_limit.remaining.is_some() {
-                        last_remaining = rate_limit.remaining;
-                    }
-
-                    // API使用状況を保存
-                    // 複数のプロジェクトを取得する場合、最後のレスポンスの情報で更新する
-                    if let Err(e) = db
-                        .save_workspace_usage(
-                            workspace.id,
-                            rate_limit.limit,
-                            rate_limit.remaining,
-                            rate_limit.reset,
-                        )
-                        .await
-                    {
-                        eprintln!("Failed to save workspace usage: {e}");
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Failed to fetch issues for project {key}: {e}");
-                    // エラーが発生しても他のプロジェクトの取得は継続
-                }
-            }
-        }
-        let me = match client.get_myself().await {
-            Ok(me) => me,
-            Err(e) => {
-                eprintln!("Failed to get myself for {domain}: {e}");
-                continue;
-            }
-        };
-
-        // ユーザー情報を更新（まだ保存されていない場合のために）
-        if workspace.user_id.is_none() || workspace.user_name.is_none() {
-            let _ = db
-                .save_workspace(WorkspaceInput {
-                    domain: domain.clone(),
-                    api_key: api_key.clone(),
-                    project_keys: project_key.clone(),
-                    user_id: Some(me.id),
-                    user_name: Some(me.name.clone()),
-                    enabled: workspace.enabled,
-                    api_limit: workspace.api_limit,
-                    api_remaining: workspace.api_remaining,
-                    api_reset: workspace.api_reset.clone(),
-                })
-                .await;
-        }
+        .map_err(|e| e.to_string())
+}
 
-        // 各課題のスコアを計算
-        for issue in &mut workspace_issues {
-            issue.relevance_score = crate::scoring::ScoringService::calculate_score(issue, &me);
-            issue.workspace_id = workspace.id;
-        }
+/// 保存済みの絞り込み条件を取得
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+///
+/// # 戻り値
+/// 保存済みフィルタのリスト（名前順）、またはエラーメッセージ
+#[tauri::command]
+pub async fn get_filters(db: State<'_, DbClient>) -> Result<Vec<crate::db::SavedFilter>, String> {
+    db.get_filters().await.map_err(|e| e.to_string())
+}
 
-        // データベースに保存
-        // Vec<String> を Vec<&str> に変換
-        let synced_projects_refs: Vec<&str> = synced_projects.iter().map(|s| s.as_str()).collect();
+/// 保存済みの絞り込み条件を削除
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `id` - フィルタID
+///
+/// # 戻り値
+/// 削除した場合は `true`（存在しなければ `false`）、またはエラーメッセージ
+#[tauri::command]
+pub async fn delete_filter(db: State<'_, DbClient>, id: i64) -> Result<bool, String> {
+    db.delete_filter(id).await.map_err(|e| e.to_string())
+}
 
-        db.save_issues(
-            workspace.id,
-            &workspace_issues,
-            &synced_projects_refs,
-            &project_keys,
-        )
+/// 課題の既読・未読を切り替え
+///
+/// 既読にした課題は一覧で確認済みとして表示され、スケジューラーの高スコア通知の対象外になる。
+/// 設定 `unread_on_update` が有効（既定）なら、次回同期で `updated` が変化した時点で未読に戻る。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `workspace_id` - ワークスペースID
+/// * `issue_id` - 課題ID
+/// * `read` - 既読にするなら `true`、未読に戻すなら `false`
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、失敗時はエラーメッセージ
+#[tauri::command]
+pub async fn mark_issue_read(
+    db: State<'_, DbClient>,
+    workspace_id: i64,
+    issue_id: i64,
+    read: bool,
+) -> Result<(), String> {
+    db.mark_issue_read(workspace_id, issue_id, read)
         .await
-        .map_err(|e| e.to_string())?;
-
-        // 保存成功後、新規・更新チケットをAIジョブとしてキュー投入する（FR-V03-004 / 手動sync経路）。
-        // 無効ワークスペースはループ冒頭で continue 済みのため、ここに来る時点で enabled が確定している。
-        // 差分検出ロジックは scheduler 経路と共通化している。
-        crate::scheduler::enqueue_changed_issues(
-            &db,
-            workspace.id,
-            &workspace_issues,
-            &existing_updated_map,
-        )
-        .await;
+        .map_err(|e| e.to_string())
+}
 
-        // v0.4: 完了課題コーパス取り込み・コメント差分取得・埋め込みジョブ投入（手動sync経路）。
-        // これらは API 直列取得（コーパス最大 MAX_CORPUS_PAGES × プロジェクト + コメント最大 N 件）を
-        // 含み、初回ビルド時は重い。通常 sync・スコアリング・保存はこの時点で完了済みのため、
-        // この重い部分は**バックグラウンドタスクへ逃がして** fetch_issues を即返す
-        // （NFR-V04-002 / NFR-V04-005: sync・UI を阻害しない）。必要データを owned へクローンして move する。
-        {
-            let db_bg = db.inner().clone();
-            let client_bg = client.clone();
-            let ws_id = workspace.id;
-            let project_keys_bg: Vec<String> = project_keys.iter().map(|s| s.to_string()).collect();
-            let issues_bg = workspace_issues.clone();
-            let updated_map_bg = existing_updated_map.clone();
-            let rate_remaining = last_remaining;
-            tauri::async_runtime::spawn(async move {
-                let pk_refs: Vec<&str> = project_keys_bg.iter().map(|s| s.as_str()).collect();
-                crate::scheduler::sync_corpus_and_embeddings(
-                    &db_bg,
-                    &client_bg,
-                    ws_id,
-                    &pk_refs,
-                    &issues_bg,
-                    &updated_map_bg,
-                    rate_remaining,
-                )
-                .await;
-            });
+/// Backlog の課題ページURLを組み立てる（例: `https://example.backlog.com/view/PROJ-123`）。
+///
+/// 課題キーは URL パスにそのまま埋め込むため、`{プロジェクトキー}-{番号}` の形式
+/// （プロジェクトキーは英数字・`_`・`-`、番号は数字のみ）に一致するものだけを受け付け、
+/// `/`・`?`・`#` などの混入による別ページへの遷移を防ぐ。
+///
+/// # 引数
+/// * `domain` - Backlogドメイン（例: "example.backlog.com"）
+/// * `issue_key` - 課題キー（例: "PROJ-123"）。前後の空白は無視する
+///
+/// # 戻り値
+/// 課題ページのURL、または課題キーが不正な場合のエラーメッセージ
+pub(crate) fn issue_url(domain: &str, issue_key: &str) -> Result<String, String> {
+    let issue_key = issue_key.trim();
+    let valid = match issue_key.rsplit_once('-') {
+        Some((project, number)) => {
+            !project.is_empty()
+                && project
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+                && !number.is_empty()
+                && number.chars().all(|c| c.is_ascii_digit())
         }
-
-        total_count += workspace_issues.len();
-        all_issues_for_tooltip.append(&mut workspace_issues);
-    }
-
-    // トレイのツールチップを更新
-    let high_priority_count = all_issues_for_tooltip
-        .iter()
-        .filter(|i| i.relevance_score >= 80)
-        .count();
-
-    // 言語設定を取得（デフォルトは日本語）
-    let lang = db
-        .get_setting("language")
-        .await
-        .unwrap_or(Some("ja".to_string()))
-        .unwrap_or("ja".to_string());
-
-    if let Some(tray) = app.tray_by_id("main") {
-        let tooltip = if high_priority_count > 0 {
-            if lang == "ja" {
-                format!("ProjectLens: 重要なチケットが {high_priority_count} 件あります")
-            } else {
-                format!("ProjectLens: {high_priority_count} important tickets")
-            }
-        } else {
-            "ProjectLens".to_string()
-        };
-        let _ = tray.set_tooltip(Some(tooltip));
+        None => false,
+    };
+    if !valid {
+        return Err(format!("Invalid issue key: {issue_key}"));
     }
-
-    Ok(total_count)
+    Ok(format!("https://{domain}/view/{issue_key}"))
 }
 
-/// プロジェクト一覧を取得するコマンド
+/// 課題ページを既定のブラウザで開く
 ///
-/// Backlog APIから自分がアクセス可能なプロジェクト一覧を取得する。
-/// 設定画面でプロジェクトを選択する際に使用。
+/// ワークスペースIDからドメインを引き、[`issue_url`] で組み立てたURLを `tauri_plugin_opener` で開く。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル（自動注入）
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `workspace_id` - 課題が属するワークスペースID
+/// * `issue_key` - 課題キー（例: "PROJ-123"）
 ///
 /// # 戻り値
-/// プロジェクト情報のベクタ（プロジェクトキーと名前）
+/// 成功時は`Ok(())`、ワークスペース未検出・課題キー不正・ブラウザ起動失敗時はエラーメッセージ
 #[tauri::command]
-pub async fn fetch_projects(
-    domain: String,
-    api_key: String,
-) -> Result<Vec<(String, String)>, String> {
-    // Backlog APIクライアントを作成
-    let client = BacklogClient::new(&domain, &api_key);
+pub async fn open_issue_in_browser(
+    app: tauri::AppHandle,
+    db: State<'_, DbClient>,
+    workspace_id: i64,
+    issue_key: String,
+) -> Result<(), String> {
+    use tauri_plugin_opener::OpenerExt;
 
-    // プロジェクト一覧を取得
-    let projects = client.get_projects().await.map_err(|e| e.to_string())?;
+    let workspaces = db.get_workspaces().await.map_err(|e| e.to_string())?;
+    let workspace = workspaces
+        .into_iter()
+        .find(|w| w.id == workspace_id)
+        .ok_or_else(|| format!("Workspace not found: {workspace_id}"))?;
 
-    // (project_key, name) のタプルに変換
-    let result: Vec<(String, String)> = projects
-        .iter()
-        .map(|p| (p.project_key.clone(), p.name.clone()))
-        .collect();
+    let url = issue_url(&workspace.domain, &issue_key)?;
+    app.opener()
+        .open_url(&url, None::<&str>)
+        .map_err(|e| format!("Failed to open {url}: {e}"))
+}
 
-    Ok(result)
+/// 課題のステータス更新の失敗をフロントへ返すメッセージにする
+///
+/// 権限不足・他の更新との衝突は、再試行しても解決しないことが分かるよう区別して伝える。
+///
+/// # 引数
+/// * `error` - [`BacklogClient::update_issue_status`] のエラー
+///
+/// # 戻り値
+/// エラーメッセージ
+fn status_update_error_message(
+    error: &(dyn std::error::Error + Send + Sync + 'static),
+) -> String {
+    use crate::backlog::ApiErrorKind;
+    match crate::backlog::api_error_kind(error) {
+        Some(ApiErrorKind::PermissionDenied) => {
+            format!("You do not have permission to change the status of this issue: {error}")
+        }
+        Some(ApiErrorKind::Conflict) => {
+            format!("The issue was updated by someone else. Sync and try again: {error}")
+        }
+        _ => error.to_string(),
+    }
 }
 
-/// 保存された課題一覧を取得
+/// 課題のステータスを変更する
 ///
-/// データベースに保存されている課題を関連度スコアの降順で取得する。
+/// Backlog の課題を更新し、更新後の課題（APIの応答）で保存済みの課題を揃える。変更後の
+/// ステータスが取得対象外（完了など）になった場合は、次回の同期を待たずに課題を削除する
+/// （ステータス一覧を取得できなかった場合は削除せず、ステータスだけ更新して残す）。
+/// 更新の成否でワークスペースのAPIキーが読み取り専用か（`is_read_only`）も記録し直す
+/// （権限不足で失敗した場合は、ユーザーのロールが閲覧のみのときだけ読み取り専用とする）。
 ///
 /// # 引数
 /// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `workspace_id` - 課題が属するワークスペースID
+/// * `issue_id` - 課題ID
+/// * `status_id` - 変更後のステータスID
 ///
 /// # 戻り値
-/// 課題のリスト（スコア順）、またはエラーメッセージ
+/// 更新後の課題。ワークスペース未検出・権限不足・衝突・保存失敗時はエラーメッセージ
 #[tauri::command]
-pub async fn get_issues(db: State<'_, DbClient>) -> Result<Vec<crate::backlog::Issue>, String> {
-    db.get_issues().await.map_err(|e| e.to_string())
+pub async fn update_issue_status(
+    db: State<'_, DbClient>,
+    workspace_id: i64,
+    issue_id: i64,
+    status_id: i64,
+) -> Result<crate::backlog::Issue, String> {
+    let workspaces = db.get_workspaces().await.map_err(|e| e.to_string())?;
+    let workspace = workspaces
+        .into_iter()
+        .find(|w| w.id == workspace_id)
+        .ok_or_else(|| format!("Workspace not found: {workspace_id}"))?;
+
+    let client = BacklogClient::new(&workspace.domain, &workspace.api_key)
+        .map_err(|e| e.to_string())?;
+    let result = client.update_issue_status(issue_id, status_id).await;
+    // 権限不足で失敗したら読み取り専用のAPIキーとして記録し、UI で書き込み操作を無効化させる。
+    // 403 はプロジェクト単位の権限不足でも返るため、ロールが閲覧のみの場合に限って記録する。
+    let read_only = match crate::backlog::read_only_after_write(&result) {
+        Some(true) => match client.get_myself().await {
+            Ok(me) => crate::backlog::is_read_only_role(me.role_type).then_some(true),
+            Err(e) => {
+                eprintln!("Failed to get myself for {}: {e}", workspace.domain);
+                None
+            }
+        },
+        other => other,
+    };
+    if let Some(read_only) = read_only {
+        if read_only != workspace.is_read_only {
+            if let Err(e) = db.set_workspace_read_only(workspace_id, read_only).await {
+                eprintln!("Failed to record read-only state of {}: {e}", workspace.domain);
+            }
+        }
+    }
+    let mut updated = result.map_err(|e| status_update_error_message(&*e))?;
+    updated.workspace_id = workspace_id;
+
+    // ステータス一覧を取得できたときだけ取得対象外と判定して削除する。既定値へのフォールバックで
+    // 判定すると、カスタムステータスの課題を誤って消してしまうため、失敗時は課題を残す
+    let project_key = project_key_from_issue_key(&updated.issue_key);
+    let is_open = match client.try_get_open_status_ids(project_key).await {
+        Ok(open_status_ids) => open_status_ids.contains(&status_id),
+        Err(e) => {
+            eprintln!("Failed to get statuses for {project_key}, keeping issue {issue_id}: {e}");
+            true
+        }
+    };
+    if !is_open {
+        db.delete_issue(workspace_id, issue_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        return Ok(updated);
+    }
+    let saved = db
+        .apply_issue_status(workspace_id, &updated)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(saved.unwrap_or(updated))
 }
 
 /// AI 機能の可用性を取得（FR-V03-002）
@@ -651,11 +2528,10 @@ pub struct SimilarIssue {
 ///
 /// 課題には専用の `project_key` カラムが無いため、`issue_key` の最後の `'-'` より前を
 /// プロジェクトキーとみなす。`'-'` を含まない異常値はキー全体をそのまま返す。
-pub(crate) fn project_key_from_issue_key(issue_key: &str) -> String {
-    match issue_key.rfind('-') {
-        Some(pos) => issue_key[..pos].to_string(),
-        None => issue_key.to_string(),
-    }
+pub(crate) fn project_key_from_issue_key(issue_key: &str) -> &str {
+    issue_key
+        .rsplit_once('-')
+        .map_or(issue_key, |(project_key, _)| project_key)
 }
 
 /// 全埋め込みから類似上位N件の `(issue_id, similarity)` を求める（純粋関数。FR-V04-004）。
@@ -758,7 +2634,7 @@ pub async fn search_similar_issues(
             Some(SimilarIssue {
                 issue_id: id,
                 workspace_id,
-                project_key: project_key_from_issue_key(&m.issue_key),
+                project_key: project_key_from_issue_key(&m.issue_key).to_string(),
                 issue_key: m.issue_key.clone(),
                 summary: m.summary.clone(),
                 status: m.status.clone(),
@@ -2015,7 +3891,7 @@ async fn collect_report_highlight_inputs(
         .map(
             |(issue_key, title, ai_summary, risk_level, delay_days, is_stale, assignee, status)| {
                 ReportHighlightInput {
-                    project_key: project_key_from_issue_key(&issue_key),
+                    project_key: project_key_from_issue_key(&issue_key).to_string(),
                     risk_level: risk_level
                         .as_deref()
                         .and_then(crate::ai::RiskLevel::from_storage_str),
@@ -2259,6 +4135,131 @@ pub async fn list_report_periods(
 mod tests {
     use super::*;
 
+    #[test]
+    fn app_config_round_trips_and_fills_defaults() {
+        let config = AppConfig {
+            language: "en".to_string(),
+            auto_sync_enabled: false,
+            sync_interval_minutes: 15,
+            notification_threshold: 60,
+            fetch_period_days: Some(30),
+            show_only_my_issues: true,
+            rate_limit_skip_threshold: 20,
+            sync_timeout_secs: 120,
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(serde_json::from_str::<AppConfig>(&json).unwrap(), config);
+
+        // 省略した項目は既定値で埋める
+        let partial: AppConfig = serde_json::from_str(r#"{"language": "en"}"#).unwrap();
+        assert_eq!(
+            partial,
+            AppConfig {
+                language: "en".to_string(),
+                ..AppConfig::default()
+            }
+        );
+
+        // 取得期間の無制限（None）は 0 として保存する
+        let settings = AppConfig::default().to_settings();
+        assert_eq!(settings.len(), 8);
+        assert_eq!(settings["fetch_period_days"], "0");
+        assert_eq!(settings["auto_sync_enabled"], "true");
+        assert_eq!(settings["sync_interval_minutes"], "5");
+        assert_eq!(settings["sync_timeout_secs"], "300");
+
+        // 未対応の言語・0 分の同期間隔は保存しない
+        assert!(config.validate().is_ok());
+        let unsupported = AppConfig {
+            language: "fr".to_string(),
+            ..AppConfig::default()
+        };
+        assert!(unsupported.validate().is_err());
+        let zero_interval = AppConfig {
+            sync_interval_minutes: 0,
+            ..AppConfig::default()
+        };
+        assert!(zero_interval.validate().is_err());
+    }
+
+    #[test]
+    fn manual_sync_skips_snoozed_workspaces_without_deleting_issues() {
+        let now = chrono::DateTime::parse_from_rfc3339("2026-10-16T09:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let workspace = |enabled: bool, snoozed_until: Option<&str>| {
+            serde_json::from_value::<crate::db::Workspace>(serde_json::json!({
+                "id": 1,
+                "domain": "ws1.backlog.com",
+                "api_key": "key",
+                "project_keys": "PROJ",
+                "user_id": null,
+                "user_name": null,
+                "enabled": enabled,
+                "api_limit": null,
+                "api_remaining": null,
+                "api_reset": null,
+                "snoozed_until": snoozed_until,
+            }))
+            .unwrap()
+        };
+        let snoozed = Some("2026-10-16T18:00:00Z");
+        let expired = Some("2026-10-16T08:00:00Z");
+
+        assert_eq!(manual_sync_action(&workspace(true, None), false, now), ManualSyncAction::Sync);
+        assert_eq!(
+            manual_sync_action(&workspace(true, expired), false, now),
+            ManualSyncAction::Sync
+        );
+        // スヌーズ中は同期せず、課題も残す
+        assert_eq!(
+            manual_sync_action(&workspace(true, snoozed), false, now),
+            ManualSyncAction::Skip
+        );
+        // 無効なワークスペースは設定に応じて課題を削除する
+        assert_eq!(
+            manual_sync_action(&workspace(false, None), false, now),
+            ManualSyncAction::DeleteIssues
+        );
+        assert_eq!(manual_sync_action(&workspace(false, None), true, now), ManualSyncAction::Skip);
+    }
+
+    #[test]
+    fn sync_skip_reasons_have_distinct_messages() {
+        let reasons = [
+            SyncSkipReason::AllProjectsFailed,
+            SyncSkipReason::AuthFailed,
+            SyncSkipReason::UserChanged,
+            SyncSkipReason::UserInfoUnavailable,
+        ];
+        let messages: std::collections::HashSet<String> =
+            reasons.iter().map(|reason| reason.message(1)).collect();
+        assert_eq!(messages.len(), reasons.len());
+        assert!(messages.iter().all(|message| message.contains("workspace 1")));
+        assert_eq!(
+            SyncSkipReason::UserInfoUnavailable.message(1),
+            "Failed to get user info for workspace 1"
+        );
+    }
+
+    #[test]
+    fn issue_url_builds_view_url_and_rejects_unsafe_keys() {
+        assert_eq!(
+            issue_url("example.backlog.com", "PROJ-123").unwrap(),
+            "https://example.backlog.com/view/PROJ-123"
+        );
+        assert_eq!(
+            issue_url("example.backlog.com", " MY_PROJ-1 ").unwrap(),
+            "https://example.backlog.com/view/MY_PROJ-1"
+        );
+        // パス・クエリの混入や形式外のキーは拒否する。
+        assert!(issue_url("example.backlog.com", "PROJ-1/../admin").is_err());
+        assert!(issue_url("example.backlog.com", "PROJ-1?x=1").is_err());
+        assert!(issue_url("example.backlog.com", "PROJ-").is_err());
+        assert!(issue_url("example.backlog.com", "PROJ").is_err());
+        assert!(issue_url("example.backlog.com", "").is_err());
+    }
+
     #[test]
     fn project_key_derivation() {
         assert_eq!(project_key_from_issue_key("PROJ-123"), "PROJ");
@@ -2397,7 +4398,7 @@ mod tests {
     ) -> ReportHighlightInput {
         ReportHighlightInput {
             issue_key: key.to_string(),
-            project_key: project_key_from_issue_key(key),
+            project_key: project_key_from_issue_key(key).to_string(),
             title: format!("Title of {key}"),
             ai_summary: format!("summary of {key}"),
             risk_level: risk,
@@ -2849,4 +4850,104 @@ mod tests {
         assert_eq!(start, "2026-12-01T00:00:00Z");
         assert_eq!(end, "2027-01-01T00:00:00Z");
     }
+
+    fn grouped_row(
+        id: i64,
+        workspace_id: i64,
+        status: Option<&str>,
+        score: i32,
+    ) -> crate::db::IssueWithWorkspace {
+        let json = format!(r#"{{ "id": {id}, "issueKey": "PROJ-{id}", "summary": "" }}"#);
+        let mut issue: crate::backlog::Issue = serde_json::from_str(&json).unwrap();
+        issue.workspace_id = workspace_id;
+        issue.relevance_score = score;
+        issue.status = status.map(|name| crate::backlog::Status {
+            id: 1,
+            name: name.to_string(),
+        });
+        crate::db::IssueWithWorkspace {
+            issue,
+            workspace_domain: format!("ws{workspace_id}.backlog.com"),
+            workspace_label: (workspace_id == 1).then(|| "本番".to_string()),
+        }
+    }
+
+    fn group_summary(groups: &[IssueGroup]) -> Vec<(Option<&str>, Vec<i64>)> {
+        groups
+            .iter()
+            .map(|group| {
+                let ids = group.issues.iter().map(|row| row.issue.id).collect();
+                (group.name.as_deref(), ids)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn group_issues_by_workspace_orders_by_total_score() {
+        let issues = vec![
+            grouped_row(1, 1, Some("未対応"), 10),
+            grouped_row(2, 2, Some("未対応"), 50),
+            grouped_row(3, 1, Some("処理中"), 30),
+            grouped_row(4, 2, None, 20),
+        ];
+        let groups = group_issues(issues, IssueGroupBy::Workspace);
+        // ラベルがあればラベル、無ければドメインをグループ名にする。
+        assert_eq!(
+            group_summary(&groups),
+            vec![
+                (Some("ws2.backlog.com"), vec![2, 4]),
+                (Some("本番"), vec![3, 1]),
+            ]
+        );
+        assert_eq!(groups[0].total_score, 70);
+        assert_eq!(groups[1].total_score, 40);
+    }
+
+    #[test]
+    fn group_issues_by_status_includes_unset_status_and_breaks_ties_by_count() {
+        let issues = vec![
+            grouped_row(1, 1, Some("未対応"), 10),
+            grouped_row(2, 2, Some("処理中"), 20),
+            grouped_row(3, 1, Some("未対応"), 10),
+            grouped_row(4, 2, None, 5),
+        ];
+        let groups = group_issues(issues, IssueGroupBy::Status);
+        // 合計が同点（20）の場合は件数の多いグループを先にする。
+        assert_eq!(
+            group_summary(&groups),
+            vec![
+                (Some("未対応"), vec![1, 3]),
+                (Some("処理中"), vec![2]),
+                (None, vec![4]),
+            ]
+        );
+        // 空のグループは作らない。
+        assert!(group_issues(Vec::new(), IssueGroupBy::Status).is_empty());
+        assert!(IssueGroupBy::parse("assignee").is_err());
+    }
+
+    #[test]
+    fn issues_response_reports_latest_sync_time_or_none() {
+        // 一度も同期していなければ None（UI で「未同期」と表示する）
+        let response = IssuesResponse::new(Vec::new(), std::collections::HashMap::new());
+        assert_eq!(response.last_synced_at, None);
+
+        let synced = std::collections::HashMap::from([
+            (1, "2026-10-16T09:00:00+00:00".to_string()),
+            (2, "2026-10-16T10:30:00+00:00".to_string()),
+        ]);
+        let response = IssuesResponse::new(Vec::new(), synced);
+        assert_eq!(response.last_synced_at.as_deref(), Some("2026-10-16T10:30:00+00:00"));
+        assert_eq!(response.workspaces_last_synced.len(), 2);
+    }
+
+    #[test]
+    fn normalize_snooze_until_accepts_rfc3339_only() {
+        assert_eq!(
+            normalize_snooze_until("2026-10-16T18:00:00+09:00").unwrap(),
+            "2026-10-16T09:00:00+00:00"
+        );
+        assert!(normalize_snooze_until("2026-10-16 18:00").is_err());
+        assert!(normalize_snooze_until("").is_err());
+    }
 }
@@ -0,0 +1,179 @@
+use crate::backlog::Issue;
+
+/// CSV の先頭に付ける BOM（Excel で開いたときに UTF-8 として認識させる）
+const UTF8_BOM: &str = "\u{feff}";
+
+/// エクスポートする列の見出し
+const HEADERS: [&str; 6] = ["issue_key", "summary", "status", "assignee", "due_date", "score"];
+
+/// 課題一覧のエクスポート形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// CSV（RFC 4180 形式のエスケープ、BOM 付き UTF-8）
+    Csv,
+    /// Markdown のテーブル（スコア降順）
+    Markdown,
+}
+
+impl ExportFormat {
+    /// 形式名からエクスポート形式を決定する
+    ///
+    /// # 引数
+    /// * `value` - 形式名（`csv` / `markdown`。大文字小文字・前後の空白は無視）
+    ///
+    /// # 戻り値
+    /// エクスポート形式。未対応の形式名は `None`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "markdown" | "md" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+}
+
+/// 課題一覧を指定した形式の文字列に整形する
+///
+/// # 引数
+/// * `issues` - エクスポートする課題
+/// * `format` - エクスポート形式
+///
+/// # 戻り値
+/// 整形した文字列
+pub fn export_issues(issues: &[Issue], format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Csv => issues_to_csv(issues),
+        ExportFormat::Markdown => issues_to_markdown(issues),
+    }
+}
+
+/// 課題1件分の列の値（[`HEADERS`] の順）
+fn row_values(issue: &Issue) -> [String; 6] {
+    [
+        issue.issue_key.clone(),
+        issue.summary.clone(),
+        issue
+            .status
+            .as_ref()
+            .map(|s| s.name.clone())
+            .unwrap_or_default(),
+        issue
+            .assignee
+            .as_ref()
+            .map(|a| a.name.clone())
+            .unwrap_or_default(),
+        // 期限日は日付部分のみ（`yyyy-MM-ddTHH:mm:ssZ` → `yyyy-MM-dd`）
+        issue
+            .due_date
+            .as_deref()
+            .and_then(|d| d.split('T').next())
+            .unwrap_or_default()
+            .to_string(),
+        issue.relevance_score.to_string(),
+    ]
+}
+
+/// CSV の1フィールドをエスケープする
+///
+/// カンマ・ダブルクォート・改行を含む場合はダブルクォートで囲み、内部のダブルクォートを二重にする。
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// 課題一覧を CSV に整形する（行区切りは CRLF、並び順は入力のまま）
+fn issues_to_csv(issues: &[Issue]) -> String {
+    let mut csv = String::from(UTF8_BOM);
+    csv.push_str(&HEADERS.join(","));
+    csv.push_str("\r\n");
+    for issue in issues {
+        let fields: Vec<String> = row_values(issue).iter().map(|v| csv_field(v)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// Markdown テーブルの1セルをエスケープする
+///
+/// `|` はセル区切りと解釈されるためエスケープし、改行は `<br>` に置き換えて1行に収める。
+fn markdown_cell(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
+/// 課題一覧をスコア降順の Markdown テーブルに整形する
+fn issues_to_markdown(issues: &[Issue]) -> String {
+    let mut sorted: Vec<&Issue> = issues.iter().collect();
+    sorted.sort_by(|a, b| b.relevance_score.cmp(&a.relevance_score));
+
+    let mut markdown = format!("| {} |\n", HEADERS.join(" | "));
+    markdown.push_str(&format!("|{}\n", " --- |".repeat(HEADERS.len())));
+    for issue in sorted {
+        let cells: Vec<String> = row_values(issue).iter().map(|v| markdown_cell(v)).collect();
+        markdown.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(key: &str, summary: &str, score: i32) -> Issue {
+        let mut issue: Issue = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "issueKey": key,
+            "summary": summary,
+            "status": { "id": 1, "name": "未対応" },
+            "assignee": { "id": 1, "name": "山田" },
+            "dueDate": "2026-05-01T00:00:00Z",
+        }))
+        .unwrap();
+        issue.relevance_score = score;
+        issue
+    }
+
+    #[test]
+    fn parse_export_format() {
+        assert_eq!(ExportFormat::parse("csv"), Some(ExportFormat::Csv));
+        assert_eq!(ExportFormat::parse(" Markdown "), Some(ExportFormat::Markdown));
+        assert_eq!(ExportFormat::parse("xlsx"), None);
+    }
+
+    #[test]
+    fn csv_escapes_commas_quotes_and_newlines() {
+        let mut plain = issue("PROJ-1", "ログイン画面の修正", 80);
+        plain.assignee = None;
+        let tricky = issue("PROJ-2", "a,b \"c\"\n次の行", 30);
+
+        let csv = export_issues(&[plain, tricky], ExportFormat::Csv);
+        assert_eq!(
+            csv,
+            "\u{feff}issue_key,summary,status,assignee,due_date,score\r\n\
+             PROJ-1,ログイン画面の修正,未対応,,2026-05-01,80\r\n\
+             PROJ-2,\"a,b \"\"c\"\"\n次の行\",未対応,山田,2026-05-01,30\r\n"
+        );
+    }
+
+    #[test]
+    fn markdown_table_is_sorted_by_score_and_escaped() {
+        let low = issue("PROJ-1", "低い", 10);
+        let high = issue("PROJ-2", "A|B\n改行", 120);
+
+        let markdown = export_issues(&[low, high], ExportFormat::Markdown);
+        assert_eq!(
+            markdown,
+            "| issue_key | summary | status | assignee | due_date | score |\n\
+             | --- | --- | --- | --- | --- | --- |\n\
+             | PROJ-2 | A\\|B<br>改行 | 未対応 | 山田 | 2026-05-01 | 120 |\n\
+             | PROJ-1 | 低い | 未対応 | 山田 | 2026-05-01 | 10 |\n"
+        );
+    }
+}
@@ -0,0 +1,124 @@
+/// 翻訳が見つからない場合に使う言語
+pub const FALLBACK_LANG: &str = "en";
+
+/// 日本語の文言
+const JA: &[(&str, &str)] = &[
+    ("tray.tooltip", "ProjectLens"),
+    ("tray.tooltip.important", "ProjectLens: 重要なチケットが {0} 件あります"),
+    ("notification.app_title", "ProjectLens"),
+    (
+        "notification.no_workspaces",
+        "ワークスペースが設定されていません。設定画面から追加してください。",
+    ),
+    ("notification.high_score.title", "ProjectLens 通知"),
+    ("notification.high_score.single", "新しい重要な課題: {0}"),
+    ("notification.high_score.multiple", "{0}件の新しい重要な課題が見つかりました。"),
+    ("notification.due_reminder.title", "ProjectLens 期限リマインド"),
+    ("notification.due_reminder.due_soon", "期限が近い課題が{0}件あります。"),
+    ("notification.due_reminder.overdue", "期限切れの課題が{0}件あります。"),
+    ("notification.score_surge.title", "ProjectLens 状況が変化した課題"),
+    ("notification.score_surge.single", "スコアが急上昇しました: {0} ({1} → {2})"),
+    ("notification.score_surge.multiple", "{0}件の課題のスコアが急上昇しました。"),
+];
+
+/// 英語の文言（未知の言語・未翻訳キーのフォールバック先）
+const EN: &[(&str, &str)] = &[
+    ("tray.tooltip", "ProjectLens"),
+    ("tray.tooltip.important", "ProjectLens: {0} important tickets"),
+    ("notification.app_title", "ProjectLens"),
+    (
+        "notification.no_workspaces",
+        "No workspaces configured. Please add one in Settings.",
+    ),
+    ("notification.high_score.title", "ProjectLens Alert"),
+    ("notification.high_score.single", "New high priority issue: {0}"),
+    ("notification.high_score.multiple", "{0} new high priority issues found."),
+    ("notification.due_reminder.title", "ProjectLens Due Reminder"),
+    ("notification.due_reminder.due_soon", "{0} issues are due soon."),
+    ("notification.due_reminder.overdue", "{0} issues are overdue."),
+    ("notification.score_surge.title", "ProjectLens Changed Issues"),
+    ("notification.score_surge.single", "Score jumped: {0} ({1} → {2})"),
+    ("notification.score_surge.multiple", "Scores of {0} issues jumped."),
+];
+
+/// 言語コードに対応する文言表を返す
+///
+/// 言語を追加する場合は文言表を定義し、ここに言語コードを追加する。
+fn catalog(lang: &str) -> Option<&'static [(&'static str, &'static str)]> {
+    match lang {
+        "ja" => Some(JA),
+        "en" => Some(EN),
+        _ => None,
+    }
+}
+
+/// 文言表からキーに対応する文言を探す
+fn lookup(
+    catalog: &'static [(&'static str, &'static str)],
+    key: &str,
+) -> Option<&'static str> {
+    catalog
+        .iter()
+        .find(|(candidate, _)| *candidate == key)
+        .map(|(_, text)| *text)
+}
+
+/// 翻訳キーに対応する文言を返す
+///
+/// 文言中の `{0}`, `{1}`, ... は `args` の同じ位置の値で置き換える。
+/// 未知の言語コードや、その言語に未翻訳のキーは英語（[`FALLBACK_LANG`]）の文言を使う。
+/// 英語にもないキーはキー文字列をそのまま返す。
+///
+/// # 引数
+/// * `key` - 翻訳キー（例: `notification.high_score.title`）
+/// * `lang` - 言語コード（`ja` / `en`）
+/// * `args` - 文言に埋め込む値
+///
+/// # 戻り値
+/// 翻訳済みの文言
+pub fn t(key: &str, lang: &str, args: &[&str]) -> String {
+    let template = catalog(lang)
+        .and_then(|catalog| lookup(catalog, key))
+        .or_else(|| catalog(FALLBACK_LANG).and_then(|catalog| lookup(catalog, key)))
+        .unwrap_or(key);
+    args.iter()
+        .enumerate()
+        .fold(template.to_string(), |text, (index, arg)| {
+            text.replace(&format!("{{{index}}}"), arg)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_language_has_the_same_keys() {
+        fn keys(catalog: &'static [(&'static str, &'static str)]) -> Vec<&'static str> {
+            let mut keys: Vec<&str> = catalog.iter().map(|(key, _)| *key).collect();
+            keys.sort_unstable();
+            keys
+        }
+        assert_eq!(keys(JA), keys(EN));
+        // キーの重複がない
+        let mut unique = keys(EN);
+        unique.dedup();
+        assert_eq!(unique.len(), EN.len());
+    }
+
+    #[test]
+    fn translates_with_args_and_falls_back_to_english() {
+        assert_eq!(
+            t("tray.tooltip.important", "ja", &["3"]),
+            "ProjectLens: 重要なチケットが 3 件あります"
+        );
+        assert_eq!(
+            t("notification.high_score.single", "en", &["PROJ-1 ログイン不具合"]),
+            "New high priority issue: PROJ-1 ログイン不具合"
+        );
+        // 未知の言語コードは英語
+        assert_eq!(t("notification.high_score.title", "zh", &[]), "ProjectLens Alert");
+        // 未知のキーはキーをそのまま返す
+        assert_eq!(t("unknown.key", "ja", &[]), "unknown.key");
+    }
+}
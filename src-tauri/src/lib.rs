@@ -3,7 +3,10 @@ mod ai; // AI推論基盤（LlmInference trait / 入出力型。v0.3）
 mod backlog; // Backlog APIクライアント
 mod commands; // Tauriコマンド（フロントエンドから呼び出される関数）
 mod db; // データベースクライアント
+mod export; // 課題一覧のエクスポート（CSV / Markdown）
+mod i18n; // ツールチップ・通知文言の翻訳
 mod log_commands; // ログ関連コマンド
+mod markup; // Backlog記法のプレーンテキスト変換
 pub mod rate_limit; // レートリミット情報
 mod scheduler; // バックグラウンドスケジューラー
 mod scoring; // スコアリングサービス
@@ -20,7 +23,18 @@ mod scoring; // スコアリングサービス
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        // 同期処理の排他ロック（定期同期と手動同期の同時実行を防ぐ）
+        .manage(scheduler::SyncLock::default())
+        // 定期同期の間隔（変化の無い同期が続くと延ばす）
+        .manage(scheduler::SyncInterval::default())
+        // 定期同期のタスクハンドル（停止・再起動と終了時の abort に使う）
+        .manage(scheduler::SchedulerHandle::default())
         .plugin(tauri_plugin_window_state::Builder::new().build())
+        // 自動起動プラグインを初期化（ログイン時起動。macOS は LaunchAgent で登録）
+        .plugin(tauri_plugin_autostart::init(
+            tauri_plugin_autostart::MacosLauncher::LaunchAgent,
+            None,
+        ))
         // 通知プラグインを初期化（システムトレイ通知用）
         .plugin(tauri_plugin_notification::init())
         // Shellプラグインを初期化（ブラウザでURLを開く用）
@@ -32,7 +46,9 @@ pub fn run() {
         // ログプラグインを初期化（デバッグ・エラーログ用）
         .plugin(
             tauri_plugin_log::Builder::new()
-                .level(tauri_plugin_log::log::LevelFilter::Info)
+                // 実際の出力レベルは setup で log::set_max_level により絞る
+                // （set_log_level コマンドで再起動なしに変更できるよう、ここでは最も詳細にしておく）
+                .level(tauri_plugin_log::log::LevelFilter::Trace)
                 .max_file_size(10_000_000) // 10MB
                 .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepAll)
                 .build(),
@@ -45,15 +61,50 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             commands::greet,                          // テスト用挨拶コマンド
             commands::save_settings,                  // 設定保存
+            commands::save_settings_batch,            // 設定の一括保存
+            commands::get_app_config,                 // 主要な設定をまとめて取得
+            commands::save_app_config,                // 主要な設定をまとめて保存
+            commands::open_settings_window,           // 設定画面を別ウィンドウで開く
             commands::get_settings,                   // 設定取得
+            commands::set_autostart,                  // ログイン時の自動起動を設定
+            commands::get_autostart,                  // ログイン時の自動起動の状態を取得
+            commands::export_settings,                // 設定・ワークスペースをJSONでエクスポート
+            commands::import_settings,                // 設定・ワークスペースをJSONからインポート
             commands::fetch_issues,                   // Backlogから課題を取得してスコアリング
+            commands::fetch_single_workspace,         // 指定ワークスペースのみ手動同期
+            commands::trigger_sync,                   // スケジューラーと同じ同期を今すぐ実行
+            commands::stop_scheduler,                 // 定期同期を停止
+            commands::start_scheduler,                // 定期同期を開始
             commands::fetch_projects,                 // Backlogからプロジェクト一覧を取得
+            commands::get_space_info,                 // Backlogスペースの情報を取得
             commands::get_issues,                     // 保存済み課題一覧を取得
+            commands::get_issues_grouped,             // ワークスペース別・ステータス別に課題を取得
+            commands::get_filtered_issues,            // 条件・保存済みフィルタで絞り込んだ課題を取得
+            commands::save_filter,                    // 絞り込み条件を名前を付けて保存
+            commands::get_filters,                    // 保存済みの絞り込み条件を取得
+            commands::delete_filter,                  // 保存済みの絞り込み条件を削除
+            commands::get_issue_stats,                // 課題数の統計を取得
+            commands::explain_issue_score,            // 課題のスコア内訳を取得
+            commands::export_issues,                  // 課題一覧をCSV/Markdownでエクスポート
+            commands::backup_database,                // データベースをバックアップ
+            commands::mark_issue_read,                // 課題の既読・未読を切り替え
+            commands::open_issue_in_browser,          // 課題ページをブラウザで開く
+            commands::update_issue_status,            // 課題のステータスを変更
             commands::get_workspaces,                 // ワークスペース一覧を取得
             commands::get_workspace_by_id,            // ワークスペースIDから取得
             commands::save_workspace,                 // ワークスペースを保存
+            commands::update_workspace_api_key,       // ワークスペースのAPIキーを更新
+            commands::accept_workspace_user_change,   // ワークスペースのユーザー変更を受け入れ
             commands::delete_workspace,               // ワークスペースを削除
+            commands::reset_all_data,                 // すべてのデータを削除して初期状態に戻す
             commands::toggle_workspace_enabled,       // ワークスペースの有効・無効を切り替え
+            commands::reorder_workspaces,             // ワークスペースの並び順を変更
+            commands::get_workspace_scoring,          // ワークスペースごとのスコアリング設定を取得
+            commands::save_workspace_scoring,         // ワークスペースごとのスコアリング設定を保存
+            commands::save_workspace_notification_threshold, // ワークスペースごとの通知しきい値を保存
+            commands::snooze_workspace,               // ワークスペースを指定時刻までスヌーズ
+            commands::snooze_all,                     // すべてのワークスペースをスヌーズ
+            commands::cancel_snooze,                  // スヌーズを解除
             commands::get_ai_availability,            // AI機能の可用性を取得（v0.3）
             commands::get_ai_settings,                // AI機能のON/OFF設定を取得（v0.3）
             commands::save_ai_setting,                // AI機能のON/OFF設定を保存（v0.3）
@@ -68,7 +119,9 @@ pub fn run() {
             commands::get_reports,                    // 保存済みレポート/サマリーを取得（v0.4.5）
             commands::list_report_periods,            // レポートの期間キー一覧を取得（v0.4.5）
             log_commands::get_log_directory,          // ログディレクトリのパスを取得
-            log_commands::open_log_directory          // ログディレクトリを開く
+            log_commands::open_log_directory,         // ログディレクトリを開く
+            log_commands::set_log_level,              // ログレベルを変更
+            log_commands::cleanup_old_logs            // 古いログファイルを削除
         ])
         // アプリケーション起動時のセットアップ処理
         .setup(|app| {
@@ -78,6 +131,17 @@ pub fn run() {
 
             let app_handle = app.handle();
 
+            // 設定値を読み込むまでは既定のログレベル（Info）で出力する
+            log::set_max_level(log_commands::DEFAULT_LOG_LEVEL);
+
+            // 古いログファイルを削除（30日より前のもの。失敗しても起動は続行する）
+            if let Err(e) = log_commands::cleanup_old_logs(
+                app_handle.clone(),
+                log_commands::STARTUP_LOG_KEEP_DAYS,
+            ) {
+                log::warn!("Failed to clean up old logs: {e}");
+            }
+
             // --- メニューの構築 ---
             // 1. アプリケーションメニュー (ProjectLens)
             let app_menu = Submenu::new(app_handle, "ProjectLens", true)?;
@@ -129,11 +193,20 @@ pub fn run() {
             let version = &app.package_info().version;
             let info_text = format!("ProjectLens v{version}");
 
+            // 同期中の無効化・表示切り替えのため、「今すぐ同期」項目はハンドルを保持する
+            let sync_now_item = MenuItem::with_id(
+                app_handle,
+                scheduler::TRAY_SYNC_NOW_ID,
+                scheduler::TRAY_SYNC_NOW_LABEL,
+                true,
+                None::<&str>,
+            )?;
             let tray_menu = Menu::with_items(
                 app_handle,
                 &[
                     &MenuItem::with_id(app_handle, "app_info", &info_text, false, None::<&str>)?,
                     &PredefinedMenuItem::separator(app_handle)?,
+                    &sync_now_item,
                     &MenuItem::with_id(app_handle, "open_lp", "Open Website", true, None::<&str>)?,
                     &PredefinedMenuItem::separator(app_handle)?,
                     &MenuItem::with_id(app_handle, "quit", "Quit", true, None::<&str>)?,
@@ -142,40 +215,23 @@ pub fn run() {
 
             // トレイアイコンをファイルから読み込み（キャッシュ回避のため）
             // dev環境では失敗する可能性があるため、失敗時はデフォルトアイコンを使用
-            let tray_icon = {
-                let icon_result =
-                    (|| -> Result<tauri::image::Image<'static>, Box<dyn std::error::Error>> {
-                        let icon_path = app_handle.path().resolve(
-                            "icons/TrayIconTemplate.png",
-                            tauri::path::BaseDirectory::Resource,
-                        )?;
-
-                        let img = image::open(&icon_path)?;
-                        let rgba = img.to_rgba8();
-                        let (width, height) = rgba.dimensions();
-                        Ok(tauri::image::Image::new_owned(
-                            rgba.into_raw(),
-                            width,
-                            height,
-                        ))
-                    })();
-
-                match icon_result {
-                    Ok(icon) => icon,
-                    Err(_) => {
+            let tray_icon =
+                scheduler::load_tray_icon(app_handle, scheduler::TrayIconState::Normal)
+                    .unwrap_or_else(|_| {
                         // フォールバック: デフォルトウィンドウアイコンを使用
                         app.default_window_icon().unwrap().clone()
-                    }
-                }
-            };
+                    });
 
             let _tray = TrayIconBuilder::with_id("main")
                 .icon(tray_icon)
-                .icon_as_template(true)
+                .icon_as_template(scheduler::TrayIconState::Normal.is_template())
                 .tooltip("ProjectLens")
                 .menu(&tray_menu)
                 .show_menu_on_left_click(false)
-                .on_menu_event(|app, event| match event.id.as_ref() {
+                .on_menu_event(move |app, event| match event.id.as_ref() {
+                    scheduler::TRAY_SYNC_NOW_ID => {
+                        scheduler::sync_from_tray(app, sync_now_item.clone());
+                    }
                     "open_lp" => {
                         use tauri_plugin_opener::OpenerExt;
                         let _ = app
@@ -233,6 +289,18 @@ pub fn run() {
                 // マイグレーションを実行
                 db_client.migrate().await.expect("failed to migrate db");
 
+                // 保存済みのログレベルを適用（未設定なら Info）
+                log_commands::apply_saved_log_level(&db_client).await;
+
+                // 保存済みのプロキシ設定を適用（未設定なら環境変数のプロキシまたは直接接続）
+                if let Ok(Some(proxy_url)) =
+                    db_client.get_setting(backlog::SETTING_PROXY_URL).await
+                {
+                    if let Err(e) = backlog::set_proxy_url(&proxy_url) {
+                        log::warn!("Ignoring saved proxy setting: {e}");
+                    }
+                }
+
                 // 起動時のキュー復旧: 前回終了時に 'processing' のまま残った AI ジョブを
                 // 'pending' へ戻し、ワーカーが再処理できるようにする（FR-V03-004）。
                 match db_client.reset_stale_jobs().await {
@@ -274,7 +342,23 @@ pub fn run() {
 
             Ok(())
         })
+        // メインウィンドウは閉じても破棄せずに隠し、トレイから再表示できるよう常駐を続ける
+        // （設定用などのサブウィンドウは閉じると破棄される）
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                if window.label() == "main" {
+                    api.prevent_close();
+                    let _ = window.hide();
+                }
+            }
+        })
         // アプリケーションを起動
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            // 終了時は定期同期のタスクを止める
+            if let tauri::RunEvent::Exit = event {
+                scheduler::stop(app);
+            }
+        });
 }
@@ -1,4 +1,74 @@
-use tauri::Manager;
+use crate::db::DbClient;
+use log::LevelFilter;
+use tauri::{Manager, State};
+
+/// ログレベルを保持する設定キー（`settings` テーブル）。
+///
+/// 値は `trace` / `debug` / `info` / `warn` / `error` のいずれか。未設定・不正値は
+/// [`DEFAULT_LOG_LEVEL`] として扱う。
+pub const SETTING_LOG_LEVEL: &str = "log_level";
+
+/// ログレベルの既定値（従来の固定値と同じ `Info`）。
+pub const DEFAULT_LOG_LEVEL: LevelFilter = LevelFilter::Info;
+
+/// ログレベル文字列を [`LevelFilter`] に変換する（大文字・小文字と前後の空白は無視）。
+///
+/// # 引数
+/// * `level` - `trace` / `debug` / `info` / `warn` / `error` のいずれか
+///
+/// # 戻り値
+/// 対応するレベル。受け付けない値なら `None`
+fn parse_log_level(level: &str) -> Option<LevelFilter> {
+    match level.trim().to_ascii_lowercase().as_str() {
+        "trace" => Some(LevelFilter::Trace),
+        "debug" => Some(LevelFilter::Debug),
+        "info" => Some(LevelFilter::Info),
+        "warn" => Some(LevelFilter::Warn),
+        "error" => Some(LevelFilter::Error),
+        _ => None,
+    }
+}
+
+/// 保存済みのログレベルを読み込んで適用する（起動時に呼ぶ）
+///
+/// ログプラグインは最も詳細な `Trace` で初期化しておき、実際の出力レベルは
+/// `log::set_max_level` で絞る。これにより再起動なしでレベルを変更できる。
+/// 未設定・不正値・取得失敗時は [`DEFAULT_LOG_LEVEL`] を適用する。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+pub async fn apply_saved_log_level(db: &DbClient) {
+    let level = match db.get_setting(SETTING_LOG_LEVEL).await {
+        Ok(Some(value)) => parse_log_level(&value).unwrap_or(DEFAULT_LOG_LEVEL),
+        Ok(None) => DEFAULT_LOG_LEVEL,
+        Err(e) => {
+            log::warn!("Failed to load log level setting: {e}");
+            DEFAULT_LOG_LEVEL
+        }
+    };
+    log::set_max_level(level);
+}
+
+/// ログレベルを変更
+///
+/// 指定レベルを即時に反映し、`settings` テーブルへ保存して次回起動時にも適用する。
+///
+/// # 引数
+/// * `db` - データベースクライアント（Tauriの状態管理から自動注入）
+/// * `level` - `trace` / `debug` / `info` / `warn` / `error` のいずれか
+///
+/// # 戻り値
+/// 成功時は`Ok(())`、不正なレベル・保存失敗時はエラーメッセージ
+#[tauri::command]
+pub async fn set_log_level(db: State<'_, DbClient>, level: String) -> Result<(), String> {
+    let filter = parse_log_level(&level).ok_or_else(|| format!("Invalid log level: {level}"))?;
+    db.save_setting(SETTING_LOG_LEVEL, &filter.to_string().to_lowercase())
+        .await
+        .map_err(|e| e.to_string())?;
+    log::set_max_level(filter);
+    log::info!("Log level changed to {filter}");
+    Ok(())
+}
 
 /// ログディレクトリのパスを取得
 ///
@@ -13,6 +83,101 @@ pub fn get_log_directory(app: tauri::AppHandle) -> Result<String, String> {
     Ok(log_dir.to_string_lossy().to_string())
 }
 
+/// 起動時の自動クリーンアップで残すログの日数
+pub const STARTUP_LOG_KEEP_DAYS: i64 = 30;
+
+/// ログプラグインが書き込み中のログファイル名（`{パッケージ名}.log`）を返す
+///
+/// ローテーション済みのファイルは別名（日時付き）になるため、この名前のファイルだけが
+/// 書き込み中のファイルになる。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+///
+/// # 戻り値
+/// 書き込み中のログファイル名
+fn active_log_file_name(app: &tauri::AppHandle) -> String {
+    format!("{}.log", app.package_info().name)
+}
+
+/// 指定ディレクトリ内の古い `.log` ファイルを削除する（純粋なファイル操作部分）。
+///
+/// ファイルの更新日時が `now - keep_days` より古いものを削除対象とする。
+/// 書き込み中のファイル（`active_file_name`）は、しばらく出力が無く古く見えても削除しない。
+/// 更新日時の取得や削除に失敗したファイル（権限不足など）は警告ログを出してスキップし、
+/// 残りのファイルの処理を続ける。
+///
+/// # 引数
+/// * `log_dir` - ログディレクトリ
+/// * `active_file_name` - 書き込み中のログファイル名（削除しない）
+/// * `keep_days` - 残す日数（0 以下なら書き込み中のもの以外のすべての `.log` が対象）
+/// * `now` - 判定の基準時刻
+///
+/// # 戻り値
+/// 削除したファイル数。ディレクトリを読めない場合はエラー
+fn remove_old_logs(
+    log_dir: &std::path::Path,
+    active_file_name: &str,
+    keep_days: i64,
+    now: std::time::SystemTime,
+) -> std::io::Result<usize> {
+    let keep = std::time::Duration::from_secs(keep_days.max(0) as u64 * 24 * 60 * 60);
+    let cutoff = now.checked_sub(keep).unwrap_or(std::time::UNIX_EPOCH);
+
+    let mut removed = 0;
+    for entry in std::fs::read_dir(log_dir)?.flatten() {
+        let path = entry.path();
+        if !path.is_file() || path.extension() != Some(std::ffi::OsStr::new("log")) {
+            continue;
+        }
+        if entry.file_name() == active_file_name {
+            continue;
+        }
+        let modified = match entry.metadata().and_then(|m| m.modified()) {
+            Ok(modified) => modified,
+            Err(e) => {
+                log::warn!("Skip log cleanup for {}: {e}", path.display());
+                continue;
+            }
+        };
+        if modified >= cutoff {
+            continue;
+        }
+        match std::fs::remove_file(&path) {
+            Ok(()) => removed += 1,
+            Err(e) => log::warn!("Failed to remove old log {}: {e}", path.display()),
+        }
+    }
+    Ok(removed)
+}
+
+/// 古いログファイルを削除
+///
+/// ログディレクトリ内で、更新日時が `keep_days` 日より古い `.log` ファイルを削除する。
+/// 書き込み中のログファイルは削除しない。個別ファイルの削除失敗はスキップし、処理全体は止めない。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `keep_days` - 残す日数
+///
+/// # 戻り値
+/// 削除したファイル数、またはエラーメッセージ
+#[tauri::command]
+pub fn cleanup_old_logs(app: tauri::AppHandle, keep_days: i64) -> Result<usize, String> {
+    let log_dir = app.path().app_log_dir().map_err(|e| e.to_string())?;
+    if !log_dir.exists() {
+        return Ok(0);
+    }
+    let active_file_name = active_log_file_name(&app);
+    let removed =
+        remove_old_logs(&log_dir, &active_file_name, keep_days, std::time::SystemTime::now())
+            .map_err(|e| e.to_string())?;
+    if removed > 0 {
+        log::info!("Removed {removed} old log file(s) older than {keep_days} day(s)");
+    }
+    Ok(removed)
+}
+
 /// ログディレクトリをFinderまたはエクスプローラーで開く
 ///
 /// ログファイルが保存されているディレクトリをシステムのファイルマネージャーで開く。
@@ -83,3 +248,39 @@ pub async fn open_log_directory(app: tauri::AppHandle) -> Result<(), String> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remove_old_logs_deletes_only_expired_log_files() {
+        let dir = std::env::temp_dir().join(format!("projectlens-logs-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("app_2026-01-01_00-00-00.log"), "log").unwrap();
+        std::fs::write(dir.join("app.log"), "log").unwrap();
+        std::fs::write(dir.join("note.txt"), "txt").unwrap();
+
+        let now = std::time::SystemTime::now();
+        // 作成直後のファイルは保持期間内のため削除しない。
+        assert_eq!(remove_old_logs(&dir, "app.log", 30, now).unwrap(), 0);
+        // 基準時刻を 31 日後に進めると .log のみ削除され、他の拡張子は残る。
+        // 書き込み中のログファイルは古く見えても削除しない。
+        let later = now + std::time::Duration::from_secs(31 * 24 * 60 * 60);
+        assert_eq!(remove_old_logs(&dir, "app.log", 30, later).unwrap(), 1);
+        assert!(!dir.join("app_2026-01-01_00-00-00.log").exists());
+        assert!(dir.join("app.log").exists());
+        assert!(dir.join("note.txt").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn parse_log_level_accepts_known_levels_only() {
+        assert_eq!(parse_log_level("debug"), Some(LevelFilter::Debug));
+        assert_eq!(parse_log_level(" WARN "), Some(LevelFilter::Warn));
+        assert_eq!(parse_log_level("trace"), Some(LevelFilter::Trace));
+        assert_eq!(parse_log_level("verbose"), None);
+        assert_eq!(parse_log_level(""), None);
+    }
+}
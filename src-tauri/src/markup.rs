@@ -0,0 +1,202 @@
+/// 見出し・箇条書き・引用として行頭から取り除く記号
+const LINE_PREFIX_MARKS: [char; 5] = ['#', '*', '-', '+', '>'];
+
+/// 文字装飾として取り除く記号（長いものから順に置き換える）
+const DECORATION_MARKS: [&str; 6] = ["'''", "''", "%%", "**", "~~", "`"];
+
+/// Backlog の記法（Backlog 記法・Markdown）をプレーンテキストに変換する
+///
+/// メンション判定や検索でノイズにならないよう、以下の記号を取り除く：
+/// - 見出し（`* 見出し` / `# 見出し`）・箇条書き（`-` / `+` / `*` / `1.`）・引用（`>`）の行頭記号
+/// - リンク（`[[表示名>URL]]` / `[[表示名:URL]]` / `[表示名](URL)` / `![代替テキスト](URL)`）は表示名だけ残す
+/// - 文字装飾（`''太字''` / `'''斜体'''` / `%%打ち消し%%` / `**太字**` / `~~打ち消し~~` / `` `コード` ``）
+/// - 文字色（`&color(red) { 文字 }`）・改行（`&br;`）
+/// - コードブロックの開始・終了行（`{code}` / `{/code}` / `` ``` ``）。ブロック内の行はそのまま残す
+/// - 表の区切り（`|`）
+///
+/// # 引数
+/// * `markup` - 記法を含むテキスト（課題の説明文など）
+///
+/// # 戻り値
+/// 記法記号を取り除いたテキスト
+pub fn to_plain_text(markup: &str) -> String {
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+    for line in markup.replace("&br;", "\n").lines() {
+        let trimmed = line.trim();
+        if is_code_fence(trimmed) {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            lines.push(line.trim_end().to_string());
+            continue;
+        }
+        let text = strip_table(strip_line_prefix(trimmed));
+        lines.push(strip_inline(&text).trim().to_string());
+    }
+    lines.join("\n").trim().to_string()
+}
+
+/// コードブロックの開始・終了行かを判定する
+fn is_code_fence(line: &str) -> bool {
+    line.starts_with("```")
+        || line == "{/code}"
+        || (line.starts_with("{code") && line.ends_with('}'))
+}
+
+/// 見出し・箇条書き・引用の行頭記号を取り除く（記号の後に空白がある場合のみ）
+fn strip_line_prefix(line: &str) -> &str {
+    let mut rest = line;
+    loop {
+        let marks = rest.trim_start_matches(LINE_PREFIX_MARKS);
+        let ordered = rest.trim_start_matches(|c: char| c.is_ascii_digit());
+        let stripped = if marks.len() < rest.len() && marks.starts_with(char::is_whitespace) {
+            marks
+        } else if ordered.len() < rest.len() && ordered.starts_with(". ") {
+            &ordered[1..]
+        } else if marks.len() < rest.len() && rest.starts_with('>') {
+            // 引用は記号の直後に空白が無くてもよい（`>引用`）
+            marks
+        } else {
+            return rest;
+        };
+        rest = stripped.trim_start();
+    }
+}
+
+/// 表の行（`|セル|セル|`、Backlog の見出し行 `|見出し|見出し|h`）のセル区切りを空白にする
+fn strip_table(line: &str) -> String {
+    if !line.starts_with('|') {
+        return line.to_string();
+    }
+    let row = line.strip_suffix("|h").unwrap_or(line);
+    row.trim_matches('|')
+        .split('|')
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 行内のリンク・文字色・文字装飾の記法を取り除く
+fn strip_inline(text: &str) -> String {
+    let text = strip_backlog_links(text);
+    let text = strip_markdown_links(&text);
+    let mut text = strip_color(&text);
+    for mark in DECORATION_MARKS {
+        text = text.replace(mark, "");
+    }
+    text
+}
+
+/// Backlog 記法のリンク（`[[表示名>URL]]` / `[[表示名:URL]]` / `[[ページ名]]`）を表示名にする
+fn strip_backlog_links(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("[[") {
+        let Some(len) = rest[start + 2..].find("]]") else {
+            break;
+        };
+        let inner = &rest[start + 2..start + 2 + len];
+        let label = match inner.split_once('>') {
+            Some((label, _)) => label,
+            None => match inner.split_once(':') {
+                Some((label, url)) if url.starts_with("http") => label,
+                _ => inner,
+            },
+        };
+        result.push_str(&rest[..start]);
+        result.push_str(label);
+        rest = &rest[start + 2 + len + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Markdown のリンク（`[表示名](URL)`）・画像（`![代替テキスト](URL)`）を表示名にする
+fn strip_markdown_links(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('[') {
+        let Some(close) = rest[start..].find(']').map(|len| start + len) else {
+            break;
+        };
+        let after = &rest[close + 1..];
+        // `]` の直後に `(URL)` が続かなければリンクではない
+        let Some(url_len) = after.strip_prefix('(').and_then(|url| url.find(')')) else {
+            result.push_str(&rest[..=close]);
+            rest = after;
+            continue;
+        };
+        let prefix = &rest[..start];
+        result.push_str(prefix.strip_suffix('!').unwrap_or(prefix));
+        result.push_str(&rest[start + 1..close]);
+        rest = &after[url_len + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 文字色の記法（`&color(red) { 文字 }`）を中の文字だけにする
+fn strip_color(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("&color(") {
+        let after = &rest[start..];
+        let Some(open) = after.find('{') else {
+            break;
+        };
+        let Some(close) = after[open..].find('}') else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        result.push_str(after[open + 1..open + close].trim());
+        rest = &after[open + close + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_links() {
+        assert_eq!(
+            to_plain_text("詳細は[[仕様書>https://example.com/spec]]と[[Wiki:https://example.com]]"),
+            "詳細は仕様書とWiki"
+        );
+        assert_eq!(to_plain_text("[[トップページ]]を参照"), "トップページを参照");
+        assert_eq!(
+            to_plain_text("[手順](https://example.com/a) ![図](https://example.com/b.png)"),
+            "手順 図"
+        );
+        // 角括弧だけの文字列はそのまま残す
+        assert_eq!(to_plain_text("[WIP] [手順](https://example.com)"), "[WIP] 手順");
+    }
+
+    #[test]
+    fn strips_headings_and_lists() {
+        let markup = "* 概要\n## 背景\n- 項目1\n-- 子項目\n+ 番号1\n1. 手順\n> 引用\n* 太字ではない";
+        assert_eq!(
+            to_plain_text(markup),
+            "概要\n背景\n項目1\n子項目\n番号1\n手順\n引用\n太字ではない"
+        );
+        // 記号の後に空白が無いものは記法ではない
+        assert_eq!(to_plain_text("-5度 2026.06"), "-5度 2026.06");
+    }
+
+    #[test]
+    fn strips_decorations_tables_and_code_fences() {
+        assert_eq!(
+            to_plain_text("''山田''さん、%%不要%%&br;&color(red) { 至急 } `code`"),
+            "山田さん、不要\n至急 code"
+        );
+        assert_eq!(to_plain_text("|名前|担当|h\n|API|山田|"), "名前 担当\nAPI 山田");
+        assert_eq!(
+            to_plain_text("{code:rust}\n  let x = 1; // * そのまま\n{/code}"),
+            "let x = 1; // * そのまま"
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 
 /// API使用状況情報
@@ -8,6 +9,41 @@ pub struct RateLimitInfo {
     pub reset: Option<String>,
 }
 
+/// レートリミットのリセット時刻文字列を日時に変換する
+///
+/// Backlog の `X-RateLimit-Reset` は UNIX 時刻（秒）だが、正規化済みの保存値（RFC3339）も
+/// 読めるよう両方の形式を受け付ける。
+///
+/// # 引数
+/// * `value` - UNIX 秒または RFC3339 形式の文字列
+///
+/// # 戻り値
+/// リセット日時（UTC）。どちらの形式にも一致しなければ `None`
+pub fn parse_reset(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<i64>() {
+        return DateTime::from_timestamp(seconds, 0);
+    }
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// レートリミットのリセット時刻を RFC3339（UTC・秒精度）へ正規化する
+///
+/// パースできない値は情報を失わないよう元の文字列のまま返す。
+///
+/// # 引数
+/// * `value` - UNIX 秒または RFC3339 形式の文字列
+///
+/// # 戻り値
+/// 正規化した文字列（例: `2026-06-01T12:00:00Z`）
+pub fn normalize_reset(value: &str) -> String {
+    parse_reset(value)
+        .map(|dt| dt.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .unwrap_or_else(|| value.to_string())
+}
+
 impl RateLimitInfo {
     pub fn empty() -> Self {
         Self {
@@ -17,6 +53,28 @@ impl RateLimitInfo {
         }
     }
 
+    /// リセット時刻を日時として取得する（[`parse_reset`] を参照）
+    pub fn reset_datetime(&self) -> Option<DateTime<Utc>> {
+        self.reset.as_deref().and_then(parse_reset)
+    }
+
+    /// 基準時刻時点の残回数を返す
+    ///
+    /// リセット時刻を過ぎていれば上限まで回復しているとみなし `limit` を返す（上限不明なら
+    /// `None`）。リセット前・リセット時刻不明の場合は保存されている `remaining` をそのまま返す。
+    ///
+    /// # 引数
+    /// * `now` - 判定の基準時刻
+    ///
+    /// # 戻り値
+    /// 残回数（不明なら `None`）
+    pub fn effective_remaining(&self, now: DateTime<Utc>) -> Option<i64> {
+        match self.reset_datetime() {
+            Some(reset) if reset <= now => self.limit,
+            _ => self.remaining,
+        }
+    }
+
     pub fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
         let limit = headers
             .get("X-RateLimit-Limit")
@@ -40,3 +98,62 @@ impl RateLimitInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reset_accepts_unix_seconds_and_rfc3339() {
+        let expected = DateTime::parse_from_rfc3339("2026-06-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(parse_reset("1780315200"), Some(expected));
+        assert_eq!(parse_reset("2026-06-01T12:00:00Z"), Some(expected));
+        assert_eq!(parse_reset("2026-06-01T21:00:00+09:00"), Some(expected));
+        assert_eq!(parse_reset("not a date"), None);
+    }
+
+    #[test]
+    fn normalize_reset_converts_to_rfc3339_utc() {
+        assert_eq!(normalize_reset("1780315200"), "2026-06-01T12:00:00Z");
+        assert_eq!(normalize_reset("2026-06-01T21:00:00+09:00"), "2026-06-01T12:00:00Z");
+        // パースできない値はそのまま残す。
+        assert_eq!(normalize_reset("unknown"), "unknown");
+    }
+
+    #[test]
+    fn reset_datetime_reads_reset_field() {
+        let info = RateLimitInfo {
+            limit: Some(150),
+            remaining: Some(100),
+            reset: Some("1780315200".to_string()),
+        };
+        assert_eq!(
+            info.reset_datetime().map(|dt| dt.timestamp()),
+            Some(1780315200)
+        );
+        assert_eq!(RateLimitInfo::empty().reset_datetime(), None);
+    }
+
+    #[test]
+    fn effective_remaining_restores_limit_after_reset() {
+        let info = RateLimitInfo {
+            limit: Some(150),
+            remaining: Some(3),
+            reset: Some("2026-06-01T12:00:00Z".to_string()),
+        };
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+        assert_eq!(info.effective_remaining(at("2026-06-01T11:59:59Z")), Some(3));
+        assert_eq!(info.effective_remaining(at("2026-06-01T12:00:00Z")), Some(150));
+
+        // 上限が不明ならリセット後は不明（None）、リセット時刻が不明なら保存値のまま。
+        let no_limit = RateLimitInfo {
+            limit: None,
+            ..info.clone()
+        };
+        assert_eq!(no_limit.effective_remaining(at("2026-06-02T00:00:00Z")), None);
+        let no_reset = RateLimitInfo { reset: None, ..info };
+        assert_eq!(no_reset.effective_remaining(at("2026-06-02T00:00:00Z")), Some(3));
+    }
+}
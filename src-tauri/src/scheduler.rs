@@ -1,9 +1,14 @@
 use crate::ai::worker::{JOB_TYPE_EMBED, JOB_TYPE_SUMMARIZE};
-use crate::backlog::BacklogClient;
+use crate::backlog::{is_auth_error, is_authentication_error, parse_backlog_date, BacklogClient};
 use crate::db::DbClient;
-use crate::scoring::ScoringService;
+use crate::i18n::t;
+use crate::scoring::{
+    ParentIssueMode, ScoringConfig, ScoringService, COMMENTED_CHECK_COUNT, COMMENT_COUNT_MIN_SCORE,
+};
 use anyhow::Result;
+use futures::StreamExt;
 use log::{debug, error, info, warn};
+use std::collections::HashMap;
 use std::time::Duration;
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_notification::NotificationExt;
@@ -13,6 +18,75 @@ use tauri_plugin_notification::NotificationExt;
 /// 未設定時は [`DEFAULT_CORPUS_MONTHS`] を用いる。設定UIから 1〜24 の範囲で更新される想定。
 pub const SETTING_CORPUS_MONTHS: &str = "corpus_months";
 
+/// 課題取得対象の期間（日数）を保持する設定キー（`settings` テーブル）。
+///
+/// 指定時は直近この日数以内に更新された課題のみを同期する。未設定・0 以下・不正値は無制限。
+/// 期間外になった課題は同期結果に含まれないため、`save_issues` のプロジェクト単位の削除で DB からも外れる。
+pub const SETTING_FETCH_PERIOD_DAYS: &str = "fetch_period_days";
+
+/// バックグラウンドの定期同期を行うかを保持する設定キー（`settings` テーブル）。
+///
+/// `"false"` のとき定期同期をスキップする（手動同期 `fetch_issues` / `trigger_sync` は常に動作する）。
+/// 未設定・不正値は有効として扱う。
+pub const SETTING_AUTO_SYNC_ENABLED: &str = "auto_sync_enabled";
+
+/// 高スコア課題として通知・件数表示する基準のスコアを保持する設定キー（`settings` テーブル）。
+///
+/// 未設定・不正値は [`DEFAULT_NOTIFICATION_THRESHOLD`]。範囲外の値は
+/// [`MIN_NOTIFICATION_THRESHOLD`]〜[`MAX_NOTIFICATION_THRESHOLD`] にクランプする。
+pub const SETTING_NOTIFICATION_THRESHOLD: &str = "notification_threshold";
+
+/// 通知しきい値の既定値
+pub const DEFAULT_NOTIFICATION_THRESHOLD: i32 = crate::db::HIGH_PRIORITY_SCORE;
+
+/// 通知しきい値の下限（0 以下ではすべての課題が通知対象になるため）
+const MIN_NOTIFICATION_THRESHOLD: i32 = 1;
+
+/// 通知しきい値の上限（加点要素をすべて満たしても届かない値で通知が止まらないよう抑える）
+const MAX_NOTIFICATION_THRESHOLD: i32 = 500;
+
+/// 同期を一時的に見送るレート残量のしきい値を保持する設定キー（`settings` テーブル）。
+///
+/// 残量がこの値未満かつリセット時刻前のワークスペースは、残量を使い切らないよう同期しない。
+/// 未設定・不正値は [`DEFAULT_RATE_LIMIT_SKIP_THRESHOLD`]。0 以下なら見送らない。
+pub const SETTING_RATE_LIMIT_SKIP_THRESHOLD: &str = "rate_limit_skip_threshold";
+
+/// 同期を見送るレート残量のしきい値の既定値
+pub(crate) const DEFAULT_RATE_LIMIT_SKIP_THRESHOLD: i64 = 10;
+
+/// スコアの急上昇とみなす上昇幅を保持する設定キー（`settings` テーブル）。
+///
+/// 前回の同期からスコアがこの値以上上がった課題を「状況が変化した課題」として通知する。
+/// 未設定・不正値は [`DEFAULT_SCORE_SURGE_THRESHOLD`]。0 以下なら通知しない。
+pub const SETTING_SCORE_SURGE_THRESHOLD: &str = "score_surge_threshold";
+
+/// スコアの急上昇とみなす上昇幅の既定値
+pub(crate) const DEFAULT_SCORE_SURGE_THRESHOLD: i32 = 50;
+
+/// 同期全体のタイムアウト（秒）を保持する設定キー（`settings` テーブル）。
+///
+/// 同期がこの時間を超えたら打ち切ってエラーにする。未設定・不正値（0 以下を含む）は
+/// [`DEFAULT_SYNC_TIMEOUT_SECS`]。
+pub const SETTING_SYNC_TIMEOUT_SECS: &str = "sync_timeout_secs";
+
+/// 同期全体のタイムアウトの既定値（秒）
+pub(crate) const DEFAULT_SYNC_TIMEOUT_SECS: u64 = 300;
+
+/// 定期同期の最短間隔（分）を保持する設定キー（`settings` テーブル）。
+///
+/// 変化の無い同期が続いて間隔を延ばす場合も、この値より短くはしない。未設定・不正値（0 以下を
+/// 含む）は [`DEFAULT_SYNC_INTERVAL_MINUTES`]。
+pub const SETTING_SYNC_INTERVAL_MINUTES: &str = "sync_interval_minutes";
+
+/// 定期同期の最短間隔の既定値（分。[`SYNC_INTERVAL_STEPS`] の最初の段階と同じ）
+pub(crate) const DEFAULT_SYNC_INTERVAL_MINUTES: u64 = 5;
+
+/// ワークスペース1件あたりの同期のタイムアウト
+///
+/// 1つのワークスペースが応答しなくても、他のワークスペースの同期を進められるようにする。
+/// 同期全体のタイムアウトの方が短ければそちらに合わせる。
+const WORKSPACE_SYNC_TIMEOUT: Duration = Duration::from_secs(120);
+
 /// 完了課題コーパス取り込み期間の既定値（月数。FR-V04-003 / 未解決事項#3 既定値）。
 ///
 /// 壁打ちの目安「3〜6ヶ月」のうち、解決ノウハウの取りこぼしを避けるため広めの 6 を既定とする。
@@ -32,12 +106,66 @@ const RATE_LIMIT_BACKOFF_THRESHOLD: i64 = 50;
 /// 初回・期間拡大時に大量取得になっても sync が長引きすぎないよう上限を設ける（残りは次サイクル）。
 const MAX_CORPUS_PAGES: i64 = 20;
 
+/// ワークスペース同期の同時実行数の上限（手動同期・定期同期で共有）
+///
+/// ワークスペースごとにレート制限は独立しているが、ネットワークを過負荷にしないよう抑える。
+pub(crate) const MAX_CONCURRENT_WORKSPACE_SYNCS: usize = 4;
+
+/// ワークスペース内のプロジェクト取得の同時実行数の上限
+const MAX_CONCURRENT_PROJECT_FETCHES: usize = 4;
+
+/// プロジェクト取得を1並列増やすのに必要なレート残量
+///
+/// 残量が [`RATE_LIMIT_BACKOFF_THRESHOLD`] 以下なら並列数にかかわらず逐次実行する。
+const REMAINING_PER_PROJECT_FETCH: i64 = 100;
+
+/// 定期同期の間隔の段階（変化の無い同期が続くほど次の段階へ延ばす。最後の段階が上限）
+const SYNC_INTERVAL_STEPS: [Duration; 3] = [
+    Duration::from_secs(60 * 5),
+    Duration::from_secs(60 * 10),
+    Duration::from_secs(60 * 30),
+];
+
+/// 同期間隔を1段階延ばすまでに続く、変化（新規・更新課題）の無い同期の回数
+const UNCHANGED_SYNCS_PER_STEP: u32 = 2;
+
+/// 同じ課題を再通知しない期間（時間）。
+///
+/// スコアが閾値を短時間に何度も跨いだ場合の重複通知を防ぐ。通知履歴は `notified_issues` に記録する。
+const NOTIFICATION_COOLDOWN_HOURS: i64 = 6;
+
+/// 同じ課題の期限を再リマインドしない期間（時間）。
+///
+/// 期限リマインドは1課題につき1日1回に抑える。通知履歴は `due_reminded_issues` に記録する。
+const DUE_REMINDER_COOLDOWN_HOURS: i64 = 24;
+
+/// 同じ課題のスコア急上昇を再通知しない期間（時間）。
+///
+/// スコアが上下を繰り返す課題で通知が続かないようにする。通知履歴は `score_surge_notified_issues` に記録する。
+const SCORE_SURGE_COOLDOWN_HOURS: i64 = 24;
+
+/// トレイメニューの「今すぐ同期」項目のID
+pub const TRAY_SYNC_NOW_ID: &str = "sync_now";
+
+/// トレイメニューの「今すぐ同期」項目の表示名
+pub const TRAY_SYNC_NOW_LABEL: &str = "Sync Now";
+
+/// 同期中のトレイメニュー項目の表示名
+const TRAY_SYNCING_LABEL: &str = "Syncing...";
+
 /// 1サイクルでコメント差分取得を行う課題数の上限（レート保護・安全弁。FR-V04-002）。
 ///
 /// 変更課題が大量にあるサイクルでも、コメント取得の API 呼び出し回数を抑える。超過分は
 /// 次サイクル以降で拾う（差分検出と embed ジョブの重複抑止により取りこぼさない）。
 const MAX_COMMENT_FETCH_PER_CYCLE: usize = 100;
 
+/// 1サイクル・1ワークスペースあたりに、直近のコメントで関与を判定する課題数
+/// （コメント数を取得し直す課題数も同じ上限）。
+///
+/// 判定は課題ごとに API を1回消費するため、新規・更新された課題に限ったうえで件数も抑える。
+/// 超過分は前回の判定を引き継ぎ、次サイクル以降で判定する。
+const MAX_COMMENTED_CHECKS_PER_CYCLE: usize = 100;
+
 /// コメント差分取得のリトライ上限（FR-V04-002）。
 ///
 /// `issue_comment_state.retry_count` がこの回数に達した課題はスキップして記録し、以降の取得を
@@ -50,26 +178,249 @@ const MAX_COMMENT_RETRIES: i64 = 3;
 /// Backlogから課題を同期し、高スコアの課題があれば通知を送る。
 ///
 /// 実行タイミング：
-/// - 初回: アプリ起動10秒後
-/// - 以降: 5分ごと
+/// - 初回: 起動直後（呼び出し元で DB マイグレーション完了後に `init` する前提）
+/// - 以降: 前回の同期から [`SyncInterval`] の間隔ごと（変化が無い同期が続くと 5分→10分→30分 と延びる）
+///
+/// 手動同期・ワークスペース追加で間隔がリセットされた場合は、待機をやり直して
+/// 最短の間隔で次の同期を行う。ワークスペース未設定時は [`sync_and_notify`] 側で
+/// 何もせずに終了する。
+///
+/// 設定 [`SETTING_AUTO_SYNC_ENABLED`] が無効のときは同期をスキップする。設定変更を即座に
+/// 反映できるよう、毎回のタイミングでフラグを確認する。
+///
+/// 実行中のタスクは [`SchedulerHandle`] に保持し、[`stop`] / [`restart`] で止め直せる。
 ///
 /// # 引数
 /// * `app` - Tauriアプリケーションハンドル
 pub fn init(app: AppHandle) {
-    tauri::async_runtime::spawn(async move {
-        let mut interval = tokio::time::interval(Duration::from_secs(60 * 5)); // 5分ごとに実行
+    start(&app, true);
+}
 
-        loop {
-            interval.tick().await;
-            info!("Scheduler: Starting sync...");
+/// 定期同期のタスクハンドル（`tauri::State` で管理する）
+///
+/// 起動・停止はこのロックを取って行い、定期同期のタスクが二重に動かないようにする。
+#[derive(Default)]
+pub struct SchedulerHandle(std::sync::Mutex<Option<tauri::async_runtime::JoinHandle<()>>>);
 
-            if let Err(e) = sync_and_notify(&app).await {
-                error!("Scheduler: Sync failed: {e}");
+/// 定期同期のタスクを起動する
+///
+/// 既に動いている場合は何もしない。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `sync_immediately` - 起動直後に1回同期するなら `true`（`false` なら間隔を待ってから）
+///
+/// # 戻り値
+/// 新たに起動した場合は `true`
+pub(crate) fn start(app: &AppHandle, sync_immediately: bool) -> bool {
+    let scheduler = app.state::<SchedulerHandle>();
+    let Ok(mut task) = scheduler.0.lock() else {
+        return false;
+    };
+    if task.as_ref().is_some_and(|t| !t.inner().is_finished()) {
+        debug!("Scheduler: Already running");
+        return false;
+    }
+    let app = app.clone();
+    *task = Some(tauri::async_runtime::spawn(async move {
+        if sync_immediately {
+            run_scheduled_sync_detached(&app).await;
+        }
+
+        let interval = app.state::<SyncInterval>();
+        loop {
+            let min_period = resolve_sync_interval(&app.state::<DbClient>()).await;
+            let period = interval.current().max(min_period);
+            debug!("Scheduler: Next sync in {}s", period.as_secs());
+            tokio::select! {
+                _ = tokio::time::sleep(period) => run_scheduled_sync_detached(&app).await,
+                // 間隔がリセットされたら、新しい間隔で待機をやり直す
+                _ = interval.reset_notify.notified() => {}
             }
         }
+    }));
+    info!("Scheduler: Started");
+    true
+}
+
+/// 定期同期のタスクを止める
+///
+/// 待機中のタスクを abort する。実行中の同期は別タスクで動いているため、途中で打ち切らずに
+/// 最後まで終わらせる（トランザクションやイベントが中途半端に残らない）。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+///
+/// # 戻り値
+/// 動いていたタスクを止めた場合は `true`
+pub(crate) fn stop(app: &AppHandle) -> bool {
+    let Some(scheduler) = app.try_state::<SchedulerHandle>() else {
+        return false;
+    };
+    let Ok(mut task) = scheduler.0.lock() else {
+        return false;
+    };
+    match task.take() {
+        Some(task) => {
+            task.abort();
+            info!("Scheduler: Stopped");
+            true
+        }
+        None => false,
+    }
+}
+
+/// 保存したときにスケジューラーを起動し直す設定キーかを判定する
+///
+/// 自動同期の切り替えと同期間隔は、待機中の間隔が終わるのを待たずに反映する。
+///
+/// # 引数
+/// * `key` - 設定キー
+///
+/// # 戻り値
+/// 起動し直す必要があれば `true`
+pub(crate) fn restarts_scheduler(key: &str) -> bool {
+    key == SETTING_AUTO_SYNC_ENABLED || key == SETTING_SYNC_INTERVAL_MINUTES
+}
+
+/// 定期同期のタスクを起動し直す
+///
+/// 設定の変更後に呼び、新しい設定で間隔を待ち直させる（再起動直後には同期しない）。
+/// [`stop`] で止めている間は起動しない（止めたままにする）。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+pub(crate) fn restart(app: &AppHandle) {
+    if stop(app) {
+        start(app, false);
+    }
+}
+
+/// 定期同期を別タスクで1回実行し、終わるまで待つ
+///
+/// スケジューラーのタスクを abort しても、実行中の同期は打ち切られない。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+async fn run_scheduled_sync_detached(app: &AppHandle) {
+    let app = app.clone();
+    let sync = tauri::async_runtime::spawn(async move { run_scheduled_sync(&app).await });
+    if let Err(e) = sync.await {
+        error!("Scheduler: Sync task failed: {e}");
+    }
+}
+
+/// 定期同期を1回実行する
+///
+/// 自動同期が無効な場合は何もしない。失敗はログに記録するのみで、次回のタイミングで再試行する。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+async fn run_scheduled_sync(app: &AppHandle) {
+    if !is_auto_sync_enabled(&app.state::<DbClient>()).await {
+        debug!("Scheduler: Auto sync is disabled. Skipping.");
+        return;
+    }
+    info!("Scheduler: Starting sync...");
+
+    if let Err(e) = run_sync(app, SyncTrigger::Scheduled).await {
+        error!("Scheduler: Sync failed: {e}");
+    }
+}
+
+/// 同期と通知を `sync-started` / `sync-finished` イベント付きで実行する
+///
+/// 定期同期と手動同期（`trigger_sync`）で共有する。[`SETTING_AUTO_SYNC_ENABLED`] は見ない。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `trigger` - 同期のきっかけ
+///
+/// # 戻り値
+/// 処理した課題の件数、またはエラーメッセージ
+pub(crate) async fn run_sync(app: &AppHandle, trigger: SyncTrigger) -> Result<usize, String> {
+    with_sync_events(app, trigger, async {
+        sync_and_notify(app).await.map_err(|e| e.to_string())
+    })
+    .await
+}
+
+/// 定期同期（[`run_sync`]）の対象のワークスペースかを判定する
+///
+/// 有効で、かつスヌーズ中でないワークスペースだけを同期する。
+///
+/// # 引数
+/// * `workspace` - 判定するワークスペース
+/// * `now` - スヌーズ期限の判定の基準時刻
+///
+/// # 戻り値
+/// 同期するなら `true`
+pub(crate) fn is_scheduled_sync_target(
+    workspace: &crate::db::Workspace,
+    now: chrono::DateTime<chrono::Utc>,
+) -> bool {
+    workspace.enabled && !workspace.is_snoozed(now)
+}
+
+/// トレイメニューから同期を実行する
+///
+/// 同期はバックグラウンドで行い、同期中はメニュー項目を無効化して「Syncing...」と表示する
+/// （完了後に元へ戻す）。結果はツールチップ・`refresh-issues` イベントなど [`run_sync`] と
+/// 同じ経路で反映される。ワークスペースが未設定の場合は同期せず、設定を促す通知を出す。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `item` - 「今すぐ同期」のメニュー項目
+pub fn sync_from_tray(app: &AppHandle, item: tauri::menu::MenuItem<tauri::Wry>) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let db = app.state::<DbClient>();
+        let has_workspaces = db
+            .get_workspaces()
+            .await
+            .map(|workspaces| !workspaces.is_empty())
+            .unwrap_or(false);
+        if !has_workspaces {
+            let lang = db
+                .get_setting("language")
+                .await
+                .ok()
+                .flatten()
+                .unwrap_or_else(|| "ja".to_string());
+            let _ = app
+                .notification()
+                .builder()
+                .title(t("notification.app_title", &lang, &[]))
+                .body(t("notification.no_workspaces", &lang, &[]))
+                .show();
+            return;
+        }
+
+        let _ = item.set_enabled(false);
+        let _ = item.set_text(TRAY_SYNCING_LABEL);
+        if let Err(e) = run_sync(&app, SyncTrigger::Manual).await {
+            warn!("Sync from tray failed: {e}");
+        }
+        let _ = item.set_text(TRAY_SYNC_NOW_LABEL);
+        let _ = item.set_enabled(true);
     });
 }
 
+/// バックグラウンドの定期同期が有効かを返す
+///
+/// [`SETTING_AUTO_SYNC_ENABLED`] が `"false"` のときだけ `false`。未設定・取得失敗は有効扱い。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+///
+/// # 戻り値
+/// 定期同期を行うなら `true`
+pub(crate) async fn is_auto_sync_enabled(db: &DbClient) -> bool {
+    db.get_setting_or(SETTING_AUTO_SYNC_ENABLED, true)
+        .await
+        .unwrap_or(true)
+}
+
 /// 同期と通知を実行
 ///
 /// 以下の処理を順に実行する：
@@ -77,7 +428,7 @@ pub fn init(app: AppHandle) {
 /// 2. Backlog APIから課題を取得
 /// 3. 現在のユーザー情報を取得
 /// 4. 各課題のスコアを計算
-/// 5. 高スコア（80点以上）の課題を抽出
+/// 5. 高スコア（通知しきい値以上）の課題を抽出
 /// 6. 課題をデータベースに保存
 /// 7. 高スコア課題があれば通知を表示
 ///
@@ -85,8 +436,8 @@ pub fn init(app: AppHandle) {
 /// * `app` - Tauriアプリケーションハンドル
 ///
 /// # 戻り値
-/// 成功時は`Ok(())`、失敗時はエラーメッセージ
-async fn sync_and_notify(app: &AppHandle) -> Result<()> {
+/// 処理した課題の件数、またはエラー
+async fn sync_and_notify(app: &AppHandle) -> Result<usize> {
     // データベースクライアントを取得
     let db = app.state::<DbClient>();
 
@@ -95,139 +446,134 @@ async fn sync_and_notify(app: &AppHandle) -> Result<()> {
 
     if workspaces.is_empty() {
         info!("Scheduler: No workspaces configured.");
-        return Ok(());
+        return Ok(0);
+    }
+
+    // オフラインなら同期全体をスキップし、次回のタイミングで改めて確認する
+    if !check_network(app, &workspaces).await {
+        return Ok(0);
     }
 
-    // 既存の課題IDとスコアを取得（通知判定用）
+    // 既存の課題IDとスコア・既読・ステータスを取得（通知判定・ステータス変化検知用）
     // あわせて updated_at を保持し、AIジョブ投入の差分検出（新規・更新分のみ）に流用する。
     let existing_issues = db.get_issues().await?;
-    let mut existing_issue_map = std::collections::HashMap::new();
-    let mut existing_updated_map: std::collections::HashMap<(i64, i64), Option<String>> =
-        std::collections::HashMap::new();
-    for issue in existing_issues {
-        existing_issue_map.insert((issue.workspace_id, issue.id), issue.relevance_score);
+    let mut existing_issue_map = HashMap::new();
+    let mut existing_updated_map: HashMap<(i64, i64), Option<String>> = HashMap::new();
+    for issue in &existing_issues {
+        existing_issue_map.insert(
+            (issue.workspace_id, issue.id),
+            (
+                issue.relevance_score,
+                issue.is_read,
+                issue.status.as_ref().map(|s| s.name.clone()),
+            ),
+        );
         existing_updated_map.insert((issue.workspace_id, issue.id), issue.updated.clone());
     }
+    // 同期の前後で比較し、追加・更新・削除された課題をフロントへ知らせる
+    let issue_snapshot = db.get_issue_snapshot().await?;
+    // 既読課題の再通知判定に用いる「更新で未読に戻す」設定。読めなければ既定（戻す）扱い。
+    let unread_on_update = db.is_unread_on_update().await.unwrap_or(true);
+
+    // 課題取得対象の期間（日数。未設定なら無制限）
+    let fetch_period_days = resolve_fetch_period_days(&db).await;
+    // 親子課題の扱い（親課題への加点を行うか）
+    let parent_issue_mode = resolve_parent_issue_mode(&db).await;
+    // スコアリングの設定（種別ごとの加点など）
+    let scoring_config = resolve_scoring_config(&db).await;
+    // 高スコアとして通知・件数表示する基準（ワークスペースごとの設定があればそちらを使う）
+    let notification_thresholds = resolve_notification_thresholds(&db).await;
+    // スコア0の課題も保存するか（読めなければ既定の保存する扱い）
+    let save_zero_score_issues = db.saves_zero_score_issues().await.unwrap_or(true);
+    // レート残量が少ないワークスペースの同期を見送る基準
+    let rate_limit_skip_threshold = resolve_rate_limit_skip_threshold(&db).await;
+    // 「状況が変化した課題」として通知するスコアの上昇幅
+    let score_surge_threshold = resolve_score_surge_threshold(&db).await;
 
     let mut all_issues_for_tooltip = Vec::new();
     let mut new_high_score_issues = Vec::new();
+    // 通知した課題（通知履歴の記録用）と、クールダウン判定・記録の基準時刻
+    let mut notified_keys: Vec<(i64, String)> = Vec::new();
+    let mut status_changes = Vec::new();
+    let mut disabled_workspaces = Vec::new();
+    let mut due_reminders = Vec::new();
+    let mut score_surges = Vec::new();
+    let mut user_changes = Vec::new();
+    let mut rate_limited_workspaces = Vec::new();
+    let mut truncated_workspaces = Vec::new();
+    let mut changed_issues = 0;
+    let notified_at = chrono::Utc::now();
 
-    for workspace in workspaces {
-        let domain = workspace.domain;
-        let api_key = workspace.api_key;
-        let project_key = workspace.project_keys;
-
-        // 2. Backlog APIから課題を取得してスコアリング
-        let client = BacklogClient::new(&domain, &api_key);
-
-        // 取得対象のステータスID（未対応:1, 処理中:2, 処理済み:3）
-        let target_status_ids = vec![1, 2, 3];
-
-        // プロジェクトキー（カンマ区切り）を分割して処理
-        let project_keys: Vec<&str> = project_key
-            .split(',')
-            .map(|k| k.trim())
-            .filter(|k| !k.is_empty())
-            .collect();
-        let mut issues = Vec::new();
-        let mut synced_projects = Vec::new();
-        // 直近のレート残量を保持し、追加のバックグラウンド取得（コーパス・コメント）の
-        // バックオフ判定に用いる（FR-V04-002 / FR-V04-003）。取得できなければ None。
-        let mut last_remaining: Option<i64> = None;
-
-        for &key in &project_keys {
-            // 各プロジェクトの課題を取得
-            match client.get_issues(key, &target_status_ids).await {
-                Ok((mut project_issues, rate_limit)) => {
-                    issues.append(&mut project_issues);
-                    synced_projects.push(key.to_string());
-                    if rate_limit.remaining.is_some() {
-                        last_remaining = rate_limit.remaining;
-                    }
-                }
-                Err(e) => {
-                    log::error!("Failed to fetch issues for project {key}: {e}");
-                }
-            }
-        }
-
-        // ユーザー情報取得
-        let me = match client.get_myself().await {
-            Ok(me) => me,
-            Err(e) => {
-                error!("Failed to get myself for {domain}: {e}");
-                continue;
-            }
-        };
-
-        // 各課題のスコアを計算
-        for issue in &mut issues {
-            let score = ScoringService::calculate_score(issue, &me);
-            issue.relevance_score = score;
-            issue.workspace_id = workspace.id;
-
-            // デバッグログ: スコア計算結果
-            debug!(
-                "Issue {} ({}): Score {}",
-                issue.issue_key, issue.summary, score
-            );
-
-            // スコアが80点以上の課題をチェック
-            if score >= 80 {
-                let should_notify = match existing_issue_map.get(&(workspace.id, issue.id)) {
-                    Some(&old_score) => {
-                        // 既存の課題: 以前は80点未満だった場合のみ通知
-                        old_score < 80
-                    }
-                    None => {
-                        // 新規の課題: 無条件で通知
-                        true
-                    }
-                };
-
-                if should_notify {
-                    info!("-> Notification target: {}", issue.issue_key);
-                    new_high_score_issues.push(format!("{} ({})", issue.summary, score));
-                }
-            }
-        }
-
-        all_issues_for_tooltip.append(&mut issues.clone());
-
-        // 3. データベースに保存
-        // Vec<String> を Vec<&str> に変換
-        let synced_projects_refs: Vec<&str> = synced_projects.iter().map(|s| s.as_str()).collect();
+    let ctx = SyncContext {
+        existing_issues: &existing_issues,
+        existing_issue_map: &existing_issue_map,
+        existing_updated_map: &existing_updated_map,
+        unread_on_update,
+        fetch_period_days,
+        parent_issue_mode,
+        scoring_config: &scoring_config,
+        notification_thresholds: &notification_thresholds,
+        save_zero_score_issues,
+        rate_limit_skip_threshold,
+        score_surge_threshold,
+        notified_at,
+        app,
+    };
 
-        match db
-            .save_issues(workspace.id, &issues, &synced_projects_refs, &project_keys)
-            .await
-        {
-            Ok(()) => {
-                // 4. 保存成功後、新規・更新チケットをAIジョブとしてキュー投入する（FR-V03-004）。
-                // 無効ワークスペースは投入対象外（scheduler は sync 自体は enabled を見ないため、
-                // ここでジョブ投入のみ enabled で絞る）。
-                if workspace.enabled {
-                    enqueue_changed_issues(&db, workspace.id, &issues, &existing_updated_map).await;
-
-                    // v0.4: 完了課題コーパスの取り込み・コメント差分取得・埋め込みジョブ投入を行う。
-                    // すべて sync・UI を阻害しないバックグラウンド処理で、失敗は本体を止めない
-                    // （NFR-V04-002 / NFR-V04-005）。レート残量が少ない場合はバックオフして次サイクルへ。
-                    sync_corpus_and_embeddings(
-                        &db,
-                        &client,
-                        workspace.id,
-                        &project_keys,
-                        &issues,
-                        &existing_updated_map,
-                        last_remaining,
-                    )
-                    .await;
-                }
-            }
-            Err(e) => {
-                error!("Failed to save issues for workspace {domain}: {e}");
-            }
-        }
+    // ワークスペースごとに独立したBacklogスペースなので並列に同期する（同時実行数は上限付き）。
+    // 結果は元の並び順で集約し、通知本文・ツールチップが実行順に左右されないようにする。
+    // 無効なワークスペース（認証失敗で自動無効化したものを含む）は同期しない。
+    // スヌーズ中のワークスペースも期限を過ぎるまで同期・通知しない（課題は削除せず残す）。
+    // タイムアウトしたワークスペースは、保存をコミットする前なら取得途中の課題を破棄して
+    // 前回の同期結果を残す（保存は1トランザクションのため、一部だけ保存されることはない）。
+    let workspace_timeout = workspace_sync_timeout(resolve_sync_timeout(&db).await);
+    let db_ref: &DbClient = &db;
+    let ctx_ref = &ctx;
+    let outcomes: Vec<Option<WorkspaceSyncOutcome>> = futures::stream::iter(workspaces)
+        .filter(|workspace| std::future::ready(is_scheduled_sync_target(workspace, notified_at)))
+        .map(|workspace| async move {
+            let domain = workspace.domain.clone();
+            let sync = async { Ok(sync_workspace(db_ref, workspace, ctx_ref).await) };
+            with_timeout(workspace_timeout, "Workspace sync", sync)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("Scheduler: {domain}: {e}");
+                    None
+                })
+        })
+        .buffered(MAX_CONCURRENT_WORKSPACE_SYNCS)
+        .collect()
+        .await;
+    for mut outcome in outcomes.into_iter().flatten() {
+        all_issues_for_tooltip.append(&mut outcome.issues);
+        new_high_score_issues.append(&mut outcome.notifications);
+        notified_keys.append(&mut outcome.notified_keys);
+        status_changes.append(&mut outcome.status_changes);
+        disabled_workspaces.extend(outcome.disabled);
+        due_reminders.append(&mut outcome.due_reminders);
+        score_surges.append(&mut outcome.score_surges);
+        user_changes.extend(outcome.user_change);
+        rate_limited_workspaces.extend(outcome.rate_limited);
+        truncated_workspaces.extend(outcome.truncated);
+        changed_issues += outcome.changed_issues;
+    }
+    // 変化が無い同期が続けば定期同期の間隔を延ばし、変化があれば最短に戻す
+    app.state::<SyncInterval>().record(changed_issues > 0);
+    // 自動無効化したワークスペースをフロントへ知らせ、ユーザーにAPIキーの更新を促す
+    for disabled in &disabled_workspaces {
+        let _ = app.emit("workspace-disabled", disabled);
+    }
+    // ユーザーが変わったワークスペースをフロントへ知らせ、更新するかの確認を促す
+    for change in &user_changes {
+        let _ = app.emit("user-changed", change);
+    }
+    // レート残量が少なく同期を見送ったワークスペースをフロントへ知らせる
+    for rate_limited in &rate_limited_workspaces {
+        let _ = app.emit("rate-limited", rate_limited);
+    }
+    // 取得件数が上限に達したワークスペースをフロントへ知らせ、絞り込みの見直しを促す
+    for truncated in &truncated_workspaces {
+        let _ = app.emit("fetch-truncated", truncated);
     }
 
     // v0.4.5: レポート/サマリーの1日1回バックグラウンド生成（FR-V045-005）。
@@ -236,10 +582,8 @@ async fn sync_and_notify(app: &AppHandle) -> Result<()> {
     generate_due_reports(app, &db).await;
 
     // トレイのツールチップを更新
-    let high_priority_count = all_issues_for_tooltip
-        .iter()
-        .filter(|i| i.relevance_score >= 80)
-        .count();
+    let high_priority_count =
+        count_high_priority(&all_issues_for_tooltip, &notification_thresholds);
 
     // 言語設定を取得（デフォルトは日本語）
     let lang = db
@@ -247,43 +591,16 @@ async fn sync_and_notify(app: &AppHandle) -> Result<()> {
         .await?
         .unwrap_or_else(|| "ja".to_string());
 
-    if let Some(tray) = app.tray_by_id("main") {
-        let tooltip = if high_priority_count > 0 {
-            if lang == "ja" {
-                format!("ProjectLens: 重要なチケットが {high_priority_count} 件あります")
-            } else {
-                format!("ProjectLens: {high_priority_count} important tickets")
-            }
-        } else {
-            "ProjectLens".to_string()
-        };
-        let _ = tray.set_tooltip(Some(tooltip));
-    }
+    update_tray_tooltip(app, &lang, high_priority_count);
 
     // 4. 新しい高スコア課題があれば通知
     if !new_high_score_issues.is_empty() {
-        let (title, body) = if lang == "ja" {
-            let title = "ProjectLens 通知";
-            let body = if new_high_score_issues.len() == 1 {
-                format!("新しい重要な課題: {}", new_high_score_issues[0])
-            } else {
-                format!(
-                    "{}件の新しい重要な課題が見つかりました。",
-                    new_high_score_issues.len()
-                )
-            };
-            (title, body)
+        let title = t("notification.high_score.title", &lang, &[]);
+        let body = if new_high_score_issues.len() == 1 {
+            t("notification.high_score.single", &lang, &[&new_high_score_issues[0]])
         } else {
-            let title = "ProjectLens Alert";
-            let body = if new_high_score_issues.len() == 1 {
-                format!("New high priority issue: {}", new_high_score_issues[0])
-            } else {
-                format!(
-                    "{} new high priority issues found.",
-                    new_high_score_issues.len()
-                )
-            };
-            (title, body)
+            let count = new_high_score_issues.len().to_string();
+            t("notification.high_score.multiple", &lang, &[&count])
         };
 
         info!("Sending notification: {body}");
@@ -297,42 +614,1710 @@ async fn sync_and_notify(app: &AppHandle) -> Result<()> {
         }
 
         // システム通知を表示
-        match app.notification().builder().title(title).body(&body).show() {
+        match app.notification().builder().title(&title).body(&body).show() {
             Ok(_) => info!("Notification sent successfully"),
             Err(e) => error!("Failed to send notification: {e}"),
         }
+
+        // 通知履歴を記録し、クールダウン中の再通知を防ぐ
+        for (workspace_id, issue_key) in &notified_keys {
+            if let Err(e) = db
+                .record_notification(*workspace_id, issue_key, notified_at)
+                .await
+            {
+                warn!("Failed to record notification for {issue_key}: {e}");
+            }
+        }
+    }
+
+    // 5. 期限切れ・期限間近の自分の課題があればリマインド（高スコア通知とは別の通知）
+    if !due_reminders.is_empty() {
+        send_due_reminders(app, &db, &lang, &due_reminders, notified_at).await;
+    }
+
+    // 6. 前回から大きくスコアが上がった課題があれば「状況が変化した課題」として通知
+    if !score_surges.is_empty() {
+        send_score_surges(app, &db, &lang, &score_surges, notified_at).await;
     }
 
     // フロントエンドに更新通知を送る（現在時刻を付与）
     let now = chrono::Local::now().format("%H:%M").to_string();
     let _ = app.emit("refresh-issues", now);
+    emit_issues_delta(app, &db, &issue_snapshot).await;
+    // ステータスが変化した既存課題があればフロントへ知らせる
+    if !status_changes.is_empty() {
+        info!("Scheduler: {} issues changed status.", status_changes.len());
+        let _ = app.emit("status-changed", &status_changes);
+    }
 
     info!(
         "Scheduler: Sync complete. {} issues processed.",
         all_issues_for_tooltip.len()
     );
 
-    Ok(())
+    Ok(all_issues_for_tooltip.len())
 }
 
-/// 同期した課題のうち、新規・更新分をAIジョブとしてキューに投入する（FR-V03-004）。
+/// 同期前の接続チェックのタイムアウト
+const NETWORK_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 直前の接続チェックの結果（オンラインなら `true`）
 ///
-/// 差分検出は同期前のDBスナップショット（`existing_updated_map`）と突き合わせて行う:
-/// - スナップショットに無い課題（初回・新規）→ 投入対象
-/// - スナップショットにあり `updated`（最終更新日時）が変化した課題 → 投入対象
-/// - `updated` が変わっていない課題 → スキップ（再分析しない）
+/// 状態が変わったときだけログを残し、オフラインが続く間に同じログを繰り返さないようにする。
+struct NetworkState(std::sync::atomic::AtomicBool);
+
+impl NetworkState {
+    /// オンラインの状態で作成する
+    const fn new() -> Self {
+        Self(std::sync::atomic::AtomicBool::new(true))
+    }
+
+    /// 接続状態を更新する
+    ///
+    /// # 引数
+    /// * `online` - 今回の接続チェックの結果
+    ///
+    /// # 戻り値
+    /// 直前の状態から変わった場合は `true`
+    fn update(&self, online: bool) -> bool {
+        self.0.swap(online, std::sync::atomic::Ordering::SeqCst) != online
+    }
+}
+
+/// プロセス全体で共有する接続状態
+static NETWORK_STATE: NetworkState = NetworkState::new();
+
+/// `network-status` イベントのペイロード
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct NetworkStatus {
+    /// Backlog へ到達できれば `true`
+    pub online: bool,
+}
+
+/// 同期前にネットワークへ接続できるかを確認する
 ///
-/// 初回同期（DBに当該ワークスペースの課題が無い状態）では全件が新規として投入される。
-/// 重複した `pending` ジョブの抑止は [`DbClient::enqueue_jobs`] 側で行うため、ここでは
-/// 投入候補のIDを集めて一括で渡す。ジョブ種別は 1行要約+リスク+提案の
-/// [`JOB_TYPE_SUMMARIZE`] を用いる。
+/// 最初の有効なワークスペース（無ければ先頭）のドメインへ [`NETWORK_CHECK_TIMEOUT`] の
+/// HEAD リクエストを送る。結果は `network-status` イベントでフロントへ通知し、オフラインへの
+/// 切り替わり・復帰は `info!` で一度だけ記録する。
 ///
-/// 投入失敗は本体（同期）を止めず、エラーログに記録するだけにとどめる（非阻害方針）。
-/// 呼び出し側で無効ワークスペースを除外している前提のため、本関数は enabled を判定しない。
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `workspaces` - 登録済みのワークスペース（空でないこと）
+///
+/// # 戻り値
+/// オンラインなら `true`
+async fn check_network(app: &AppHandle, workspaces: &[crate::db::Workspace]) -> bool {
+    let Some(workspace) = workspaces
+        .iter()
+        .find(|w| w.enabled)
+        .or_else(|| workspaces.first())
+    else {
+        return true;
+    };
+    let client = match BacklogClient::new(&workspace.domain, &workspace.api_key) {
+        Ok(client) => client,
+        Err(e) => {
+            // プロキシ設定の問題はオフラインではないため、同期側でエラーとして扱う
+            error!("Scheduler: Failed to create Backlog client for network check: {e}");
+            return true;
+        }
+    };
+    let online = client.is_reachable(NETWORK_CHECK_TIMEOUT).await;
+
+    if NETWORK_STATE.update(online) {
+        if online {
+            info!("Scheduler: Network is back online. Resuming sync.");
+        } else {
+            info!("Scheduler: Network appears to be offline. Skipping sync until it recovers.");
+        }
+    }
+    let _ = app.emit("network-status", NetworkStatus { online });
+    online
+}
+
+/// 自分宛のお知らせがあった課題IDを取得する（メンション判定用）
+///
+/// 取得に失敗した場合は `None` を返し、スコアリングは説明文マッチにフォールバックする。
 ///
 /// # 引数
-/// * `db` - データベースクライアント
-/// * `workspace_id` - 対象ワークスペースID
+/// * `client` - ワークスペースのBacklog APIクライアント
+///
+/// # 戻り値
+/// 課題IDの集合、または取得できなければ `None`
+pub(crate) async fn fetch_notified_issue_ids(
+    client: &BacklogClient,
+) -> Option<std::collections::HashSet<i64>> {
+    match client.get_notifications().await {
+        Ok(notifications) => Some(ScoringService::notified_issue_ids(&notifications)),
+        Err(e) => {
+            warn!("Failed to get notifications, falling back to description match: {e}");
+            None
+        }
+    }
+}
+
+/// 1回の同期でコメント数を取得し直す課題の判定（[`fetch_comment_bonus`] で使う）
+///
+/// [`mark_commented_issues`] と同じく、取得し直すのは同期前のスナップショットから新規・更新された
+/// 課題だけにする。更新の無い課題、[`MAX_COMMENTED_CHECKS_PER_CYCLE`] を超えた課題、
+/// レート残量が少ないときは前回のコメント数を引き継ぐ。
+pub(crate) struct CommentCountChecks<'a> {
+    workspace_id: i64,
+    /// 前回の同期で取得したコメント数（課題ID → 件数）
+    previous: HashMap<i64, i64>,
+    existing_updated_map: &'a HashMap<(i64, i64), Option<String>>,
+    backoff: bool,
+    /// 取得し直した課題数
+    checks: usize,
+}
+
+impl<'a> CommentCountChecks<'a> {
+    /// 前回の同期で取得したコメント数を読み込んで判定を始める
+    ///
+    /// # 引数
+    /// * `db` - データベースクライアント（前回のコメント数の取得用）
+    /// * `workspace_id` - ワークスペースID
+    /// * `existing_updated_map` - 同期前のDBスナップショット `(workspace_id, issue_id) -> updated`
+    /// * `remaining` - 直近のレート残量
+    pub(crate) async fn load(
+        db: &DbClient,
+        workspace_id: i64,
+        existing_updated_map: &'a HashMap<(i64, i64), Option<String>>,
+        remaining: Option<i64>,
+    ) -> Self {
+        let previous = db.get_comment_counts(workspace_id).await.unwrap_or_else(|e| {
+            warn!("Failed to get comment counts for workspace {workspace_id}: {e}");
+            Default::default()
+        });
+        Self {
+            workspace_id,
+            previous,
+            existing_updated_map,
+            backoff: is_rate_backoff(remaining),
+            checks: 0,
+        }
+    }
+
+    /// コメント数を API で取得し直すか判定する
+    ///
+    /// 取得し直さない場合は前回のコメント数を `issue.comment_count` に設定する。
+    ///
+    /// # 戻り値
+    /// 取得し直すなら `true`
+    fn should_fetch(&mut self, issue: &mut crate::backlog::Issue) -> bool {
+        let unchanged = self
+            .existing_updated_map
+            .get(&(self.workspace_id, issue.id))
+            .is_some_and(|updated| *updated == issue.updated);
+        if unchanged || self.backoff || self.checks >= MAX_COMMENTED_CHECKS_PER_CYCLE {
+            issue.comment_count = self.previous.get(&issue.id).copied();
+            return false;
+        }
+        self.checks += 1;
+        true
+    }
+}
+
+/// スコアが一定以上の課題のコメント数を取得し、コメント数による加点を返す
+///
+/// API 消費を抑えるため、`score` が [`COMMENT_COUNT_MIN_SCORE`] 未満の課題は取得しない。
+/// 取得し直すのは新規・更新された課題に限り、それ以外は前回のコメント数で加点する
+/// （[`CommentCountChecks`]）。取得したコメント数は `issue.comment_count` に保持する。
+/// 取得に失敗した場合は加点しない。
+///
+/// # 引数
+/// * `client` - ワークスペースのBacklog APIクライアント
+/// * `checks` - 取得し直す課題の判定
+/// * `issue` - 対象の課題
+/// * `score` - コメント数を除いたスコア
+///
+/// # 戻り値
+/// コメント数による加点
+pub(crate) async fn fetch_comment_bonus(
+    client: &BacklogClient,
+    checks: &mut CommentCountChecks<'_>,
+    issue: &mut crate::backlog::Issue,
+    score: i32,
+) -> i32 {
+    if score < COMMENT_COUNT_MIN_SCORE {
+        return 0;
+    }
+    if !checks.should_fetch(issue) {
+        return issue.comment_count.map_or(0, ScoringService::comment_bonus);
+    }
+    match client.get_issue_comment_count(issue.id).await {
+        Ok(count) => {
+            issue.comment_count = Some(count);
+            ScoringService::comment_bonus(count)
+        }
+        Err(e) => {
+            warn!("Failed to get comment count for {}: {e}", issue.issue_key);
+            0
+        }
+    }
+}
+
+/// 直近のコメントに自分の投稿があるかを判定し、[`Issue::commented_by_me`] に設定する
+///
+/// 同じコメントから自分がメンションされた回数も数え、[`Issue::comment_mentions`] に設定する。
+/// API 消費を抑えるため、コメントを取得するのは同期前のスナップショットから新規・更新された
+/// 課題だけにする（コメントが付くと課題の `updated` も変わる）。更新の無い課題、
+/// [`MAX_COMMENTED_CHECKS_PER_CYCLE`] を超えた課題、レート残量が少ないときは前回の判定を引き継ぐ。
+/// コメントを取得できなかった課題は `None` とし、関与による加点をしない。
+///
+/// # 引数
+/// * `db` - データベースクライアント（前回の判定結果の取得用）
+/// * `client` - ワークスペースのBacklog APIクライアント
+/// * `workspace_id` - ワークスペースID
+/// * `me` - 自分のユーザー情報
+/// * `issues` - 同期で取得した課題
+/// * `existing_updated_map` - 同期前のDBスナップショット `(workspace_id, issue_id) -> updated`
+/// * `remaining` - 直近のレート残量
+///
+/// [`Issue::commented_by_me`]: crate::backlog::Issue::commented_by_me
+/// [`Issue::comment_mentions`]: crate::backlog::Issue::comment_mentions
+pub(crate) async fn mark_commented_issues(
+    db: &DbClient,
+    client: &BacklogClient,
+    workspace_id: i64,
+    me: &crate::backlog::User,
+    issues: &mut [crate::backlog::Issue],
+    existing_updated_map: &HashMap<(i64, i64), Option<String>>,
+    remaining: Option<i64>,
+) {
+    let previous = db
+        .get_commented_issue_ids(workspace_id)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Failed to get commented issues for workspace {workspace_id}: {e}");
+            Default::default()
+        });
+    let previous_mentions = db
+        .get_comment_mention_counts(workspace_id)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Failed to get comment mentions for workspace {workspace_id}: {e}");
+            Default::default()
+        });
+    let backoff = is_rate_backoff(remaining);
+    let mut checks = 0;
+    for issue in issues.iter_mut() {
+        let key = (workspace_id, issue.id);
+        let unchanged = existing_updated_map
+            .get(&key)
+            .is_some_and(|updated| *updated == issue.updated);
+        if unchanged || backoff || checks >= MAX_COMMENTED_CHECKS_PER_CYCLE {
+            issue.commented_by_me = existing_updated_map
+                .contains_key(&key)
+                .then_some(previous.contains(&issue.id));
+            issue.comment_mentions = previous_mentions.get(&issue.id).copied();
+            continue;
+        }
+        checks += 1;
+        match client
+            .get_recent_comments(issue.id, COMMENTED_CHECK_COUNT)
+            .await
+        {
+            Ok(comments) => {
+                issue.commented_by_me = Some(ScoringService::has_commented(&comments, me.id));
+                issue.comment_mentions = Some(ScoringService::comment_mentions(&comments, me));
+            }
+            Err(e) => {
+                warn!("Failed to get recent comments for {}: {e}", issue.issue_key);
+                issue.commented_by_me = None;
+                issue.comment_mentions = None;
+            }
+        }
+    }
+}
+
+/// ウォッチ中の課題を同期結果に取り込む
+///
+/// 取得済みの課題と重複するものは `issue.id` で突き合わせて [`Issue::watching`] を立てるだけにし、
+/// 取得済みでない課題は同期できたプロジェクトの未完了の課題に限って追加する（それ以外は
+/// 保存時のクリーンアップで削除されてしまうため）。取得に失敗した場合は何もしない。
+///
+/// # 引数
+/// * `client` - ワークスペースのBacklog APIクライアント
+/// * `user_id` - 自分のユーザーID
+/// * `issues` - 同期で取得した課題（ウォッチ中の課題を追加する）
+/// * `synced_project_keys` - 同期に成功したプロジェクトキー
+///
+/// [`Issue::watching`]: crate::backlog::Issue::watching
+pub(crate) async fn merge_watching_issues(
+    client: &BacklogClient,
+    user_id: i64,
+    issues: &mut Vec<crate::backlog::Issue>,
+    synced_project_keys: &[String],
+) {
+    let watching_issues = match client.get_watching_issues(user_id).await {
+        Ok(watching_issues) => watching_issues,
+        Err(e) => {
+            warn!("Failed to get watching issues: {e}");
+            return;
+        }
+    };
+
+    for watched in watching_issues {
+        if let Some(issue) = issues.iter_mut().find(|i| i.id == watched.id) {
+            issue.watching = true;
+            continue;
+        }
+        let Some((project_key, _)) = watched.issue_key.rsplit_once('-') else {
+            continue;
+        };
+        if !synced_project_keys.iter().any(|k| k == project_key) {
+            continue;
+        }
+        let open_status_ids = client.get_open_status_ids(project_key).await;
+        let is_open = watched
+            .status
+            .as_ref()
+            .is_some_and(|s| open_status_ids.contains(&s.id));
+        if is_open {
+            issues.push(watched);
+        }
+    }
+}
+
+/// 同期のきっかけ（`sync-started` / `sync-finished` イベントのペイロード）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncTrigger {
+    /// 手動同期（`fetch_issues` / `fetch_single_workspace`）
+    Manual,
+    /// スケジューラによる定期同期
+    Scheduled,
+}
+
+/// `sync-finished` イベントのペイロード
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SyncFinished {
+    pub trigger: SyncTrigger,
+    /// 処理した課題の件数（失敗時は 0）
+    pub count: usize,
+    /// 所要時間（ミリ秒）
+    pub duration_ms: u64,
+    /// 失敗で終了したら `true`
+    pub failed: bool,
+    /// 失敗時のエラーメッセージ
+    pub error: Option<String>,
+}
+
+/// 同期結果から `sync-finished` に載せる課題の件数を取り出す
+pub(crate) trait SyncedCount {
+    /// 同期した課題の件数
+    fn synced_count(&self) -> usize;
+}
+
+impl SyncedCount for usize {
+    fn synced_count(&self) -> usize {
+        *self
+    }
+}
+
+/// 同期の実行待ちを打ち切るまでの時間
+///
+/// 先行する同期がこの時間内に終わらなければ、後から来た同期はスキップしてエラーを返す。
+const SYNC_LOCK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// 同期処理の排他ロック（`tauri::State` で管理する）
+///
+/// 定期同期と手動同期（`fetch_issues` / `fetch_single_workspace` / `trigger_sync`）が同じ
+/// ワークスペースへ二重に API を呼び、`save_issues` が競合するのを防ぐ。ロックは全体で1つだけ
+/// 持ち、入れ子で取得しないためデッドロックしない。
+#[derive(Debug, Default)]
+pub struct SyncLock(tokio::sync::Mutex<()>);
+
+/// 同期結果の変化に応じて定期同期の間隔を決める
+///
+/// 変化（新規・更新課題）の無い同期が [`UNCHANGED_SYNCS_PER_STEP`] 回続くごとに
+/// [`SYNC_INTERVAL_STEPS`] の次の段階へ延ばし、変化があれば最短の間隔に戻す。
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AdaptiveInterval {
+    /// 変化の無い同期が続いた回数
+    unchanged_syncs: u32,
+}
+
+impl AdaptiveInterval {
+    /// 次の定期同期までの間隔
+    pub fn current(&self) -> Duration {
+        let step = (self.unchanged_syncs / UNCHANGED_SYNCS_PER_STEP) as usize;
+        SYNC_INTERVAL_STEPS[step.min(SYNC_INTERVAL_STEPS.len() - 1)]
+    }
+
+    /// 同期結果を記録する
+    ///
+    /// # 引数
+    /// * `changed` - 新規・更新課題があったなら `true`
+    pub fn record(&mut self, changed: bool) {
+        self.unchanged_syncs = if changed {
+            0
+        } else {
+            self.unchanged_syncs.saturating_add(1)
+        };
+    }
+
+    /// 最短の間隔に戻す
+    pub fn reset(&mut self) {
+        self.unchanged_syncs = 0;
+    }
+}
+
+/// 定期同期の間隔（`tauri::State` で管理する）
+///
+/// 同期結果は [`sync_and_notify`] が記録する。手動同期（[`with_sync_events`]）と
+/// ワークスペース追加で [`reset`](Self::reset) し、待機中のスケジューラーを起こす。
+#[derive(Debug, Default)]
+pub struct SyncInterval {
+    interval: std::sync::Mutex<AdaptiveInterval>,
+    /// 間隔のリセットをスケジューラーへ知らせる
+    reset_notify: tokio::sync::Notify,
+}
+
+impl SyncInterval {
+    /// 次の定期同期までの間隔
+    fn current(&self) -> Duration {
+        self.interval.lock().map_or(SYNC_INTERVAL_STEPS[0], |i| i.current())
+    }
+
+    /// 同期結果を記録する
+    fn record(&self, changed: bool) {
+        if let Ok(mut interval) = self.interval.lock() {
+            interval.record(changed);
+        }
+    }
+
+    /// 最短の間隔に戻し、待機中の定期同期をその間隔で待ち直させる
+    pub fn reset(&self) {
+        if let Ok(mut interval) = self.interval.lock() {
+            interval.reset();
+        }
+        self.reset_notify.notify_one();
+    }
+}
+
+/// 同期処理の前後で `sync-started` / `sync-finished` イベントを発火する
+///
+/// 複数ワークスペースを並列に処理する場合も、同期全体を `sync` として渡すことで
+/// 開始・終了を一度ずつ発火する。エラー終了時も `failed: true` で必ず `sync-finished` を送る。
+///
+/// 実行中は [`SyncLock`] を保持する。他の同期が実行中なら終了を待ち、[`SYNC_LOCK_TIMEOUT`] を
+/// 過ぎても終わらなければ `sync` を実行せずにエラーを返す（このときイベントは発火しない）。
+///
+/// `sync` が [`SETTING_SYNC_TIMEOUT_SECS`] の時間内に終わらなければ打ち切ってエラーを返す。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `trigger` - 同期のきっかけ
+/// * `sync` - 同期処理（結果の件数は [`SyncedCount`] で取り出す）
+///
+/// # 戻り値
+/// `sync` の結果をそのまま返す
+pub(crate) async fn with_sync_events<R: tauri::Runtime, T: SyncedCount>(
+    app: &AppHandle<R>,
+    trigger: SyncTrigger,
+    sync: impl std::future::Future<Output = Result<T, String>>,
+) -> Result<T, String> {
+    let lock = app.state::<SyncLock>();
+    let Ok(_guard) = tokio::time::timeout(SYNC_LOCK_TIMEOUT, lock.0.lock()).await else {
+        warn!("Another sync is still running. Skipping {trigger:?} sync.");
+        return Err("Another sync is still running".to_string());
+    };
+
+    let sync_timeout = match app.try_state::<DbClient>() {
+        Some(db) => resolve_sync_timeout(&db).await,
+        None => Duration::from_secs(DEFAULT_SYNC_TIMEOUT_SECS),
+    };
+
+    let _ = app.emit("sync-started", trigger);
+    let started = std::time::Instant::now();
+
+    let result = with_timeout(sync_timeout, "Sync", sync).await;
+
+    let payload = SyncFinished {
+        trigger,
+        count: result.as_ref().map_or(0, SyncedCount::synced_count),
+        duration_ms: started.elapsed().as_millis() as u64,
+        failed: result.is_err(),
+        error: result.as_ref().err().cloned(),
+    };
+    let _ = app.emit("sync-finished", payload);
+
+    // 手動同期の後は、定期同期の間隔を最短に戻す
+    if trigger == SyncTrigger::Manual {
+        if let Some(interval) = app.try_state::<SyncInterval>() {
+            interval.reset();
+        }
+    }
+    result
+}
+
+/// ワークスペース同期で共有する、同期前のスナップショットと設定値
+struct SyncContext<'a> {
+    /// 同期前の課題（取得結果から消えた課題の検知用）
+    existing_issues: &'a [crate::backlog::Issue],
+    /// 同期前の `(workspace_id, issue_id) -> (スコア, 既読, ステータス名)`（通知判定・変化検知用）
+    existing_issue_map: &'a HashMap<(i64, i64), (i32, bool, Option<String>)>,
+    /// 同期前の `(workspace_id, issue_id) -> updated`（差分検出用）
+    existing_updated_map: &'a HashMap<(i64, i64), Option<String>>,
+    /// 更新で既読を未読に戻すか
+    unread_on_update: bool,
+    /// 課題取得対象の期間（日数。`None` で無制限）
+    fetch_period_days: Option<i64>,
+    /// 親子課題の扱い
+    parent_issue_mode: ParentIssueMode,
+    /// スコアリングの設定
+    scoring_config: &'a ScoringConfig,
+    /// 高スコアとして通知する基準
+    notification_thresholds: &'a NotificationThresholds,
+    /// スコア0の課題も保存するか
+    save_zero_score_issues: bool,
+    /// 同期を見送るレート残量のしきい値
+    rate_limit_skip_threshold: i64,
+    /// スコアの急上昇とみなす上昇幅（0 以下なら検知しない）
+    score_surge_threshold: i32,
+    /// クールダウン判定の基準時刻
+    notified_at: chrono::DateTime<chrono::Utc>,
+    /// API使用状況の通知に使うTauriアプリケーションハンドル
+    app: &'a AppHandle,
+}
+
+/// 1ワークスペース分の同期結果
+struct WorkspaceSyncOutcome {
+    /// スコアリング済みの課題
+    issues: Vec<crate::backlog::Issue>,
+    /// 通知本文に載せる課題（`要約 (スコア)`）
+    notifications: Vec<String>,
+    /// 通知履歴に記録する `(workspace_id, issue_key)`
+    notified_keys: Vec<(i64, String)>,
+    /// ステータスが変化した既存課題
+    status_changes: Vec<StatusChange>,
+    /// 認証失敗で自動無効化した場合の通知内容
+    disabled: Option<WorkspaceDisabled>,
+    /// 期限をリマインドする自分の課題
+    due_reminders: Vec<DueReminder>,
+    /// 前回の同期からスコアが急上昇した課題
+    score_surges: Vec<ScoreSurge>,
+    /// APIキーのユーザーが保存済みのユーザーと異なり、同期を見送った場合の通知内容
+    user_change: Option<UserChange>,
+    /// レート残量が少なく同期を見送った場合の通知内容
+    rate_limited: Option<RateLimited>,
+    /// 取得件数が上限に達したプロジェクトがあった場合の通知内容
+    truncated: Option<FetchTruncated>,
+    /// 同期前のスナップショットから新規・更新された課題の件数（同期間隔の調整用）
+    changed_issues: usize,
+}
+
+impl WorkspaceSyncOutcome {
+    /// 認証失敗で自動無効化したワークスペースの同期結果（課題・通知は空）
+    fn disabled(disabled: WorkspaceDisabled) -> Self {
+        Self {
+            issues: Vec::new(),
+            notifications: Vec::new(),
+            notified_keys: Vec::new(),
+            status_changes: Vec::new(),
+            disabled: Some(disabled),
+            due_reminders: Vec::new(),
+            score_surges: Vec::new(),
+            user_change: None,
+            rate_limited: None,
+            truncated: None,
+            changed_issues: 0,
+        }
+    }
+
+    /// ユーザーが変わったため同期を見送ったワークスペースの同期結果（課題・通知は空）
+    fn user_changed(change: UserChange) -> Self {
+        Self {
+            issues: Vec::new(),
+            notifications: Vec::new(),
+            notified_keys: Vec::new(),
+            status_changes: Vec::new(),
+            disabled: None,
+            due_reminders: Vec::new(),
+            score_surges: Vec::new(),
+            user_change: Some(change),
+            rate_limited: None,
+            truncated: None,
+            changed_issues: 0,
+        }
+    }
+
+    /// レート残量が少ないため同期を見送ったワークスペースの同期結果（課題・通知は空）
+    fn rate_limited(rate_limited: RateLimited) -> Self {
+        Self {
+            issues: Vec::new(),
+            notifications: Vec::new(),
+            notified_keys: Vec::new(),
+            status_changes: Vec::new(),
+            disabled: None,
+            due_reminders: Vec::new(),
+            score_surges: Vec::new(),
+            user_change: None,
+            rate_limited: Some(rate_limited),
+            truncated: None,
+            changed_issues: 0,
+        }
+    }
+}
+
+/// ワークスペースのユーザー変化（`user-changed` イベントのペイロード）
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct UserChange {
+    pub workspace_id: i64,
+    pub domain: String,
+    /// 保存済みのユーザーID
+    pub previous_user_id: i64,
+    /// 保存済みのユーザー名
+    pub previous_user_name: Option<String>,
+    /// APIキーで取得したユーザーID
+    pub current_user_id: i64,
+    /// APIキーで取得したユーザー名
+    pub current_user_name: String,
+}
+
+/// APIキーで取得したユーザーが保存済みのユーザーと異なるかを判定する
+///
+/// 別ユーザーのAPIキーを誤って入力すると、スコアリングが別人基準になってしまうため検知する。
+/// 保存済みのユーザーIDが無い（新規・旧バージョンで未保存）場合は変化とみなさない。
+///
+/// # 引数
+/// * `workspace_id` - ワークスペースID
+/// * `domain` - ワークスペースのドメイン
+/// * `previous_user_id` - 保存済みのユーザーID
+/// * `previous_user_name` - 保存済みのユーザー名
+/// * `me` - APIキーで取得したユーザー
+///
+/// # 戻り値
+/// ユーザーが異なる場合は変化の内容、同じなら `None`
+pub(crate) fn detect_user_change(
+    workspace_id: i64,
+    domain: &str,
+    previous_user_id: Option<i64>,
+    previous_user_name: Option<&str>,
+    me: &crate::backlog::User,
+) -> Option<UserChange> {
+    let previous_user_id = previous_user_id?;
+    if previous_user_id == me.id {
+        return None;
+    }
+    Some(UserChange {
+        workspace_id,
+        domain: domain.to_string(),
+        previous_user_id,
+        previous_user_name: previous_user_name.map(str::to_string),
+        current_user_id: me.id,
+        current_user_name: me.name.clone(),
+    })
+}
+
+/// レート残量の枯渇を避けるため同期を見送ったワークスペース（`rate-limited` イベントのペイロード）
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct RateLimited {
+    pub workspace_id: i64,
+    pub domain: String,
+    /// 前回の同期時点のレート残量
+    pub remaining: i64,
+    /// レートのリセット時刻（RFC3339）。この時刻を過ぎると同期を再開する
+    pub reset: String,
+}
+
+/// 取得件数が上限に達し、一部の課題しか取得できていない可能性があるワークスペース
+/// （`fetch-truncated` イベントのペイロード）
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct FetchTruncated {
+    pub workspace_id: i64,
+    pub domain: String,
+    /// 取得件数が上限に達したプロジェクトキー
+    pub project_keys: Vec<String>,
+    /// 1プロジェクトあたりの取得件数の上限
+    pub limit: i64,
+}
+
+/// `sync-progress` イベントの名前
+pub(crate) const SYNC_PROGRESS_EVENT: &str = "sync-progress";
+
+/// ワークスペースごとの同期の進捗（`sync-progress` イベントのペイロード）
+///
+/// フロントでプログレスバーや「3/5 同期中」を表示するために使う。
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct SyncProgress {
+    /// 同期を終えたワークスペースの数（終了のイベントでは、このワークスペースを含む）
+    pub current: usize,
+    /// 同期するワークスペースの総数
+    pub total: usize,
+    /// 開始・終了したワークスペースの表示名
+    pub workspace_label: String,
+    /// 終了のイベントなら `true`、開始のイベントなら `false`
+    pub finished: bool,
+}
+
+/// 並列に同期するワークスペースの進捗を数える
+///
+/// 完了したワークスペースの数をアトミックカウンタで数え、終了した順に `current` を1つずつ増やす。
+#[derive(Debug)]
+pub(crate) struct SyncProgressTracker {
+    total: usize,
+    completed: std::sync::atomic::AtomicUsize,
+}
+
+impl SyncProgressTracker {
+    /// 同期するワークスペースの総数を指定して作る
+    pub(crate) fn new(total: usize) -> Self {
+        Self {
+            total,
+            completed: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    /// ワークスペースの同期を開始したときの進捗
+    ///
+    /// # 引数
+    /// * `workspace_label` - ワークスペースの表示名
+    pub(crate) fn started(&self, workspace_label: &str) -> SyncProgress {
+        SyncProgress {
+            current: self.completed.load(std::sync::atomic::Ordering::SeqCst),
+            total: self.total,
+            workspace_label: workspace_label.to_string(),
+            finished: false,
+        }
+    }
+
+    /// ワークスペースの同期を終えたときの進捗（成否に関わらず数える）
+    ///
+    /// # 引数
+    /// * `workspace_label` - ワークスペースの表示名
+    pub(crate) fn finished(&self, workspace_label: &str) -> SyncProgress {
+        let completed = self.completed.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+        SyncProgress {
+            current: completed,
+            total: self.total,
+            workspace_label: workspace_label.to_string(),
+            finished: true,
+        }
+    }
+}
+
+/// レート残量が少ないため同期を見送るべきかを判定する
+///
+/// 残量がしきい値未満で、かつリセット時刻前の場合に見送る。残量・リセット時刻が不明な場合は
+/// 見送らない（いつ再開するか判断できず、同期が止まったままになるのを避ける）。
+///
+/// # 引数
+/// * `workspace_id` - ワークスペースID
+/// * `domain` - ワークスペースのドメイン
+/// * `remaining` - 保存済みのレート残量
+/// * `reset` - 保存済みのリセット時刻
+/// * `threshold` - 見送るしきい値（[`SETTING_RATE_LIMIT_SKIP_THRESHOLD`]）
+/// * `now` - 判定の基準時刻
+///
+/// # 戻り値
+/// 見送る場合はその内容、同期してよければ `None`
+pub(crate) fn detect_rate_limited(
+    workspace_id: i64,
+    domain: &str,
+    remaining: Option<i64>,
+    reset: Option<&str>,
+    threshold: i64,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<RateLimited> {
+    let remaining = remaining.filter(|remaining| *remaining < threshold)?;
+    let reset = reset
+        .and_then(crate::rate_limit::parse_reset)
+        .filter(|reset| *reset > now)?;
+    Some(RateLimited {
+        workspace_id,
+        domain: domain.to_string(),
+        remaining,
+        reset: reset.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+    })
+}
+
+/// API使用状況の更新（`rate-limit-updated` イベントのペイロード）
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RateLimitUpdated {
+    pub workspace_id: i64,
+    /// 最新のレート情報（リセット時刻は RFC3339。リセット時刻を過ぎていれば残量は上限値）
+    pub rate_limit: crate::rate_limit::RateLimitInfo,
+}
+
+/// レスポンスのレート情報から `rate-limit-updated` イベントのペイロードを作る
+///
+/// 保存値（[`DbClient::get_workspaces`]）と同じ見え方になるよう、リセット時刻を正規化し、
+/// リセット時刻を過ぎていれば残量を上限値に戻す。
+///
+/// # 引数
+/// * `workspace_id` - ワークスペースID
+/// * `rate_limit` - レスポンスヘッダから取り込んだレート情報
+/// * `now` - 判定の基準時刻
+///
+/// # 戻り値
+/// イベントのペイロード
+pub(crate) fn rate_limit_updated(
+    workspace_id: i64,
+    rate_limit: crate::rate_limit::RateLimitInfo,
+    now: chrono::DateTime<chrono::Utc>,
+) -> RateLimitUpdated {
+    let remaining = rate_limit.effective_remaining(now);
+    RateLimitUpdated {
+        workspace_id,
+        rate_limit: crate::rate_limit::RateLimitInfo {
+            limit: rate_limit.limit,
+            remaining,
+            reset: rate_limit.reset.map(|r| crate::rate_limit::normalize_reset(&r)),
+        },
+    }
+}
+
+/// ワークスペースのAPI使用状況を保存し、`rate-limit-updated` イベントでフロントへ知らせる
+///
+/// 複数プロジェクトを取得する場合はレスポンスごとに呼び、残量の推移を即時に反映させる。
+/// 保存に失敗した場合はログに記録するのみで、イベントは送らない。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `db` - データベースクライアント
+/// * `workspace_id` - ワークスペースID
+/// * `rate_limit` - レスポンスヘッダから取り込んだレート情報
+pub(crate) async fn save_workspace_usage<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+    db: &DbClient,
+    workspace_id: i64,
+    rate_limit: crate::rate_limit::RateLimitInfo,
+) {
+    if let Err(e) = db
+        .save_workspace_usage(
+            workspace_id,
+            rate_limit.limit,
+            rate_limit.remaining,
+            rate_limit.reset.clone(),
+        )
+        .await
+    {
+        error!("Failed to save workspace usage: {e}");
+        return;
+    }
+    let payload = rate_limit_updated(workspace_id, rate_limit, chrono::Utc::now());
+    let _ = app.emit("rate-limit-updated", payload);
+}
+
+/// ワークスペースを自動無効化した理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkspaceDisabledReason {
+    /// APIキーの認証に失敗した（HTTP 401 / 403）
+    AuthFailed,
+}
+
+/// `workspace-disabled` イベントのペイロード
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct WorkspaceDisabled {
+    pub workspace_id: i64,
+    pub domain: String,
+    pub reason: WorkspaceDisabledReason,
+    /// 失敗したAPI呼び出しのエラー内容（APIキーはマスク済み）
+    pub message: String,
+}
+
+/// 同期でステータスが変化した課題（`status-changed` イベントのペイロード要素）
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct StatusChange {
+    pub workspace_id: i64,
+    pub issue_id: i64,
+    pub issue_key: String,
+    pub summary: String,
+    /// 同期前のステータス名（未設定なら `None`）
+    pub old_status: Option<String>,
+    /// 同期後のステータス名（未設定なら `None`）
+    pub new_status: Option<String>,
+    /// 取得対象（未完了）から外れた（完了など）なら `true`。このとき `new_status` は `None`
+    pub closed: bool,
+}
+
+/// 同期前後の課題スナップショット（`(workspace_id, id) -> (updated_at, relevance_score)`）
+pub(crate) type IssueSnapshot = HashMap<(i64, i64), (Option<String>, i32)>;
+
+/// 差分イベントで変化を伝える課題
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+pub struct IssueRef {
+    pub workspace_id: i64,
+    pub issue_id: i64,
+}
+
+/// 同期による課題の差分（`issues-delta` イベントのペイロード）
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct IssuesDelta {
+    /// 新たに追加された課題
+    pub added: Vec<IssueRef>,
+    /// 更新日時またはスコアが変わった課題
+    pub updated: Vec<IssueRef>,
+    /// 削除された課題
+    pub removed: Vec<IssueRef>,
+}
+
+impl IssuesDelta {
+    /// 変化が無ければ `true`
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// 同期前後のスナップショットを比較し、追加・更新・削除された課題を求める
+///
+/// 更新日時かスコアのどちらかが変わった課題を更新とみなす。各リストは
+/// `(workspace_id, issue_id)` の昇順に並べる。
+///
+/// # 引数
+/// * `before` - 保存前のスナップショット
+/// * `after` - 保存後のスナップショット
+///
+/// # 戻り値
+/// 課題の差分
+pub(crate) fn diff_issue_snapshots(before: &IssueSnapshot, after: &IssueSnapshot) -> IssuesDelta {
+    let issue_ref = |&(workspace_id, issue_id): &(i64, i64)| IssueRef {
+        workspace_id,
+        issue_id,
+    };
+    let mut delta = IssuesDelta::default();
+    for (key, value) in after {
+        match before.get(key) {
+            None => delta.added.push(issue_ref(key)),
+            Some(previous) if previous != value => delta.updated.push(issue_ref(key)),
+            Some(_) => {}
+        }
+    }
+    delta.removed = before
+        .keys()
+        .filter(|key| !after.contains_key(key))
+        .map(issue_ref)
+        .collect();
+    delta.added.sort_unstable();
+    delta.updated.sort_unstable();
+    delta.removed.sort_unstable();
+    delta
+}
+
+/// 同期前のスナップショットと比較した課題の差分を `issues-delta` イベントで送る
+///
+/// フロントが一覧を全件取得し直さず、変化した課題だけを反映できるようにする。
+/// 変化が無い場合・保存後のスナップショットを取得できない場合は送らない。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `db` - データベースクライアント
+/// * `before` - 保存前のスナップショット（[`DbClient::get_issue_snapshot`]）
+pub(crate) async fn emit_issues_delta(app: &AppHandle, db: &DbClient, before: &IssueSnapshot) {
+    let after = match db.get_issue_snapshot().await {
+        Ok(after) => after,
+        Err(e) => {
+            warn!("Failed to get issue snapshot: {e}");
+            return;
+        }
+    };
+    let delta = diff_issue_snapshots(before, &after);
+    if !delta.is_empty() {
+        let _ = app.emit("issues-delta", &delta);
+    }
+}
+
+/// 同期前のスナップショットと比べて、課題が新規または更新されたかを判定する
+///
+/// # 引数
+/// * `ctx` - 同期前のスナップショット
+/// * `issue` - 同期した課題（`workspace_id` 設定済み）
+///
+/// # 戻り値
+/// スナップショットに無い、または `updated` が変わっていれば `true`
+fn is_new_or_updated(ctx: &SyncContext<'_>, issue: &crate::backlog::Issue) -> bool {
+    let key = (issue.workspace_id, issue.id);
+    if !ctx.existing_issue_map.contains_key(&key) {
+        return true;
+    }
+    ctx.existing_updated_map
+        .get(&key)
+        .is_some_and(|updated| *updated != issue.updated)
+}
+
+/// 同期前後のステータスを比較し、変化していれば [`StatusChange`] を返す
+///
+/// 新規課題（同期前に存在しない）は変化として扱わない。`None` から値へ、値から `None` への
+/// 変化も検知する。なお同期対象は未完了のステータスのみのため、完了へ移った課題は取得結果に
+/// 現れずここでは検知されない（[`closed_status_changes`] で検知する）。
+///
+/// # 引数
+/// * `issue` - 同期後の課題
+/// * `old_status` - 同期前のステータス名（課題が存在しなかった場合は `None`）
+///
+/// # 戻り値
+/// 変化があれば `Some(StatusChange)`、なければ `None`
+fn status_change(
+    issue: &crate::backlog::Issue,
+    old_status: Option<&Option<String>>,
+) -> Option<StatusChange> {
+    let old_status = old_status?;
+    let new_status = issue.status.as_ref().map(|s| s.name.clone());
+    if *old_status == new_status {
+        return None;
+    }
+    Some(StatusChange {
+        workspace_id: issue.workspace_id,
+        issue_id: issue.id,
+        issue_key: issue.issue_key.clone(),
+        summary: issue.summary.clone(),
+        old_status: old_status.clone(),
+        new_status,
+        closed: false,
+    })
+}
+
+/// 取得対象（未完了）から外れた既存課題を、完了などへのステータス変化として返す
+///
+/// 同期対象は未完了のステータスのみのため、完了へ移った課題は取得結果から消える。
+/// 取得に成功したプロジェクトの課題に限り、取得件数が上限に達したプロジェクトは
+/// 取得しきれなかっただけの可能性があるため除く。
+///
+/// # 引数
+/// * `workspace_id` - ワークスペースID
+/// * `existing_issues` - 同期前の課題（全ワークスペース分）
+/// * `fetched_ids` - 今回取得できた課題のID
+/// * `synced_projects` - 取得に成功したプロジェクトキー
+/// * `truncated_projects` - 取得件数が上限に達したプロジェクトキー
+///
+/// # 戻り値
+/// 取得対象から外れた課題の変化（`closed = true`）
+fn closed_status_changes(
+    workspace_id: i64,
+    existing_issues: &[crate::backlog::Issue],
+    fetched_ids: &std::collections::HashSet<i64>,
+    synced_projects: &[String],
+    truncated_projects: &[String],
+) -> Vec<StatusChange> {
+    let contains_key = |keys: &[String], project_key: &str| {
+        keys.iter().any(|key| key.eq_ignore_ascii_case(project_key))
+    };
+    existing_issues
+        .iter()
+        .filter(|issue| issue.workspace_id == workspace_id && !fetched_ids.contains(&issue.id))
+        .filter(|issue| {
+            let project_key = crate::commands::project_key_from_issue_key(&issue.issue_key);
+            contains_key(synced_projects, project_key)
+                && !contains_key(truncated_projects, project_key)
+        })
+        .map(|issue| StatusChange {
+            workspace_id,
+            issue_id: issue.id,
+            issue_key: issue.issue_key.clone(),
+            summary: issue.summary.clone(),
+            old_status: issue.status.as_ref().map(|s| s.name.clone()),
+            new_status: None,
+            closed: true,
+        })
+        .collect()
+}
+
+/// 1ワークスペース分の課題を取得・スコアリング・保存し、通知対象を判定する
+///
+/// 保存は [`DbClient::save_issues`] のワークスペース単位のトランザクションで行うため、
+/// 複数ワークスペースを並列に同期しても書き込みが混ざらない。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+/// * `workspace` - 同期するワークスペース
+/// * `ctx` - 同期前のスナップショットと設定値
+///
+/// # 戻り値
+/// 同期結果。全プロジェクトの取得に失敗した場合やユーザー情報を取得できずスキップした場合は
+/// `None`（前回の課題は残す）
+async fn sync_workspace(
+    db: &DbClient,
+    workspace: crate::db::Workspace,
+    ctx: &SyncContext<'_>,
+) -> Option<WorkspaceSyncOutcome> {
+    // レート残量を使い切らないよう、残量が少なければリセット時刻まで同期を見送る
+    if let Some(rate_limited) = detect_rate_limited(
+        workspace.id,
+        &workspace.domain,
+        workspace.api_remaining,
+        workspace.api_reset.as_deref(),
+        ctx.rate_limit_skip_threshold,
+        ctx.notified_at,
+    ) {
+        info!(
+            "Skipping sync for {}: rate limit remaining {} until {}",
+            rate_limited.domain, rate_limited.remaining, rate_limited.reset
+        );
+        return Some(WorkspaceSyncOutcome::rate_limited(rate_limited));
+    }
+
+    let domain = workspace.domain;
+    let api_key = workspace.api_key;
+    let project_key = workspace.project_keys;
+
+    // 2. Backlog APIから課題を取得してスコアリング
+    let client = match BacklogClient::new(&domain, &api_key) {
+        Ok(client) => client,
+        Err(e) => {
+            error!("Failed to create Backlog client for {domain}: {e}");
+            return None;
+        }
+    };
+
+    // プロジェクトキー（カンマ区切り）を分割して処理
+    let project_keys = crate::db::split_project_keys(&project_key);
+    let mut issues = Vec::new();
+    let mut notifications = Vec::new();
+    let mut notified_keys = Vec::new();
+    let mut status_changes = Vec::new();
+    let mut due_reminders = Vec::new();
+    let mut score_surges = Vec::new();
+    let mut synced_projects = Vec::new();
+    let mut auth_failed_projects = Vec::new();
+    let mut truncated_projects = Vec::new();
+    let mut changed_issues = 0;
+    // 直近のレート残量を保持し、追加のバックグラウンド取得（コーパス・コメント）の
+    // バックオフ判定に用いる（FR-V04-002 / FR-V04-003）。取得できなければ None。
+    let mut last_remaining: Option<i64> = None;
+
+    // 各プロジェクトの課題を取得（レート残量に応じて並列数を調整）
+    let fetched = fetch_project_issues(
+        &client,
+        &project_keys,
+        ctx.fetch_period_days,
+        workspace.api_remaining,
+    )
+    .await;
+    for (key, result) in fetched {
+        match result {
+            Ok((mut project_issues, rate_limit)) => {
+                if crate::backlog::is_fetch_truncated(project_issues.len()) {
+                    warn!("Issues for project {key} reached the fetch limit; some may be missing");
+                    truncated_projects.push(key.to_string());
+                }
+                issues.append(&mut project_issues);
+                synced_projects.push(key.to_string());
+                if rate_limit.remaining.is_some() {
+                    last_remaining = rate_limit.remaining;
+                }
+                // API使用状況をレスポンスごとに保存し、残量の推移をフロントへ知らせる
+                save_workspace_usage(ctx.app, db, workspace.id, rate_limit).await;
+            }
+            // プロジェクト単位の 403 は権限の無いプロジェクトだけの問題のことがあるため、
+            // ここでは無効化せず、全プロジェクトが認証エラーになった場合に限って無効化する
+            Err(e) if is_auth_error(&*e) => {
+                warn!("Authentication failed for project {key} of {domain}: {e}");
+                auth_failed_projects.push((key.to_string(), e));
+            }
+            Err(e) => {
+                log::error!("Failed to fetch issues for project {key}: {e}");
+            }
+        }
+    }
+    if let Some((_, e)) = all_project_fetches_failed_with_auth(&project_keys, &auth_failed_projects)
+    {
+        return disable_workspace_on_auth_failure(db, workspace.id, &domain, &**e)
+            .await
+            .map(WorkspaceSyncOutcome::disabled);
+    }
+    // 全プロジェクトの取得に失敗した場合は、前回の課題を残したまま同期を見送る
+    if all_project_fetches_failed(&project_keys, &synced_projects) {
+        warn!("Failed to fetch issues for all projects of {domain}. Keeping previous issues");
+        return None;
+    }
+
+    // ユーザー情報取得
+    let me = match client.get_myself().await {
+        Ok(me) => me,
+        // APIキーの無効（401）に限って無効化する（権限不足の 403 では無効化しない）
+        Err(e) if is_authentication_error(&*e) => {
+            return disable_workspace_on_auth_failure(db, workspace.id, &domain, &*e)
+                .await
+                .map(WorkspaceSyncOutcome::disabled);
+        }
+        Err(e) => {
+            error!("Failed to get myself for {domain}: {e}");
+            return None;
+        }
+    };
+
+    // 保存済みと別のユーザーなら、別人基準でスコアリング・保存しないよう同期を見送る
+    // （ユーザーが確認して accept_workspace_user_change で更新するまで続く）
+    if let Some(change) = detect_user_change(
+        workspace.id,
+        &domain,
+        workspace.user_id,
+        workspace.user_name.as_deref(),
+        &me,
+    ) {
+        warn!(
+            "User of {domain} changed from {} to {}. Skipping sync",
+            change.previous_user_id, change.current_user_id
+        );
+        return Some(WorkspaceSyncOutcome::user_changed(change));
+    }
+
+    // ウォッチ中の課題を取り込む（担当者でなくても把握したい課題。取得済みの課題とは重複しない）
+    merge_watching_issues(&client, me.id, &mut issues, &synced_projects).await;
+    // 自分がコメントした課題を判定する（関与している課題への加点用）
+    mark_commented_issues(
+        db,
+        &client,
+        workspace.id,
+        &me,
+        &mut issues,
+        ctx.existing_updated_map,
+        last_remaining,
+    )
+    .await;
+
+    // 子課題を持つ親課題のID（親課題加点モード用）
+    let parent_ids = ScoringService::parent_issue_ids(&issues);
+    // 自分宛のお知らせ（取得できなければ説明文マッチにフォールバック）
+    let notified_ids = fetch_notified_issue_ids(&client).await;
+
+    // ワークスペースごとの設定があればグローバルの設定を上書きする
+    let scoring_config =
+        resolve_workspace_scoring_config(db, ctx.scoring_config, workspace.id).await;
+
+    // 期限リマインドの判定基準日（スコアリングと同じタイムゾーンの暦日）
+    let today = scoring_config.today(chrono::Utc::now());
+
+    // 各課題のスコアを計算
+    let mut comment_checks =
+        CommentCountChecks::load(db, workspace.id, ctx.existing_updated_map, last_remaining).await;
+    for issue in &mut issues {
+        let mut score = ScoringService::calculate_score_with_notifications(
+            issue,
+            &me,
+            notified_ids.as_ref(),
+            &scoring_config,
+        );
+        if ctx.parent_issue_mode == ParentIssueMode::ParentBonus {
+            score += ScoringService::parent_bonus(issue, &parent_ids);
+        }
+        score += fetch_comment_bonus(&client, &mut comment_checks, issue, score).await;
+        issue.relevance_score = score;
+        issue.workspace_id = workspace.id;
+
+        let previous = ctx.existing_issue_map.get(&(workspace.id, issue.id));
+        if is_new_or_updated(ctx, issue) {
+            changed_issues += 1;
+        }
+        if let Some(change) = status_change(issue, previous.map(|(_, _, status)| status)) {
+            debug!(
+                "Status changed: {} {:?} -> {:?}",
+                change.issue_key, change.old_status, change.new_status
+            );
+            status_changes.push(change);
+        }
+
+        // デバッグログ: スコア計算結果
+        debug!(
+            "Issue {} ({}): Score {}",
+            issue.issue_key, issue.summary, score
+        );
+
+        // 自分が担当の期限切れ・期限間近の課題は、スコアに関わらず期限リマインドの対象にする
+        let is_mine = issue.assignee.as_ref().is_some_and(|a| a.id == me.id);
+        if let Some(state) = due_state(issue, today).filter(|_| is_mine) {
+            let in_cooldown = db
+                .is_due_reminder_in_cooldown(
+                    workspace.id,
+                    &issue.issue_key,
+                    ctx.notified_at,
+                    chrono::Duration::hours(DUE_REMINDER_COOLDOWN_HOURS),
+                )
+                .await
+                .unwrap_or(false);
+            if !in_cooldown {
+                due_reminders.push(DueReminder {
+                    workspace_id: workspace.id,
+                    issue_key: issue.issue_key.clone(),
+                    state,
+                });
+            }
+        }
+
+        // スコアが通知しきい値（ワークスペースごとの設定があればその値）以上の課題をチェック
+        let notification_threshold = ctx.notification_thresholds.for_workspace(workspace.id);
+        if score >= notification_threshold {
+            let should_notify = match previous {
+                Some(&(old_score, was_read, _)) => {
+                    // 既存の課題: 既読（確認済み）でなく、以前はしきい値未満だった場合のみ通知
+                    let old_updated = ctx
+                        .existing_updated_map
+                        .get(&(workspace.id, issue.id))
+                        .and_then(|u| u.as_deref());
+                    let still_read = is_still_read(
+                        was_read,
+                        ctx.unread_on_update,
+                        old_updated,
+                        issue.updated.as_deref(),
+                    );
+                    !still_read && old_score < notification_threshold
+                }
+                None => {
+                    // 新規の課題: 無条件で通知
+                    true
+                }
+            };
+
+            // 直近に通知済みの課題はクールダウンが過ぎるまで再通知しない
+            let in_cooldown = should_notify
+                && db
+                    .is_notification_in_cooldown(
+                        workspace.id,
+                        &issue.issue_key,
+                        ctx.notified_at,
+                        chrono::Duration::hours(NOTIFICATION_COOLDOWN_HOURS),
+                    )
+                    .await
+                    .unwrap_or(false);
+            if in_cooldown {
+                debug!("-> Skip notification (cooldown): {}", issue.issue_key);
+            } else if should_notify {
+                info!("-> Notification target: {}", issue.issue_key);
+                notifications.push(format!("{} ({})", issue.summary, score));
+                notified_keys.push((workspace.id, issue.issue_key.clone()));
+            }
+        }
+
+        // 前回から大きくスコアが上がった課題は、状況が急変したものとして別に知らせる
+        let previous_score = previous.map(|&(old_score, _, _)| old_score);
+        if is_score_surge(previous_score, score, ctx.score_surge_threshold) {
+            let in_cooldown = db
+                .is_score_surge_in_cooldown(
+                    workspace.id,
+                    &issue.issue_key,
+                    ctx.notified_at,
+                    chrono::Duration::hours(SCORE_SURGE_COOLDOWN_HOURS),
+                )
+                .await
+                .unwrap_or(false);
+            if in_cooldown {
+                debug!("-> Skip score surge notification (cooldown): {}", issue.issue_key);
+            } else {
+                score_surges.push(ScoreSurge {
+                    workspace_id: workspace.id,
+                    issue_key: issue.issue_key.clone(),
+                    summary: issue.summary.clone(),
+                    previous_score: previous_score.unwrap_or_default(),
+                    score,
+                });
+            }
+        }
+    }
+
+    // 取得結果から消えた（完了などへ移った）既存課題もステータスの変化として知らせる
+    let fetched_ids: std::collections::HashSet<i64> = issues.iter().map(|i| i.id).collect();
+    status_changes.extend(closed_status_changes(
+        workspace.id,
+        ctx.existing_issues,
+        &fetched_ids,
+        &synced_projects,
+        &truncated_projects,
+    ));
+
+    // 3. データベースに保存
+    // 設定によりスコア0の課題は保存しない（取得できた課題なので削除対象にはしない）
+    let retained_ids = if ctx.save_zero_score_issues {
+        Vec::new()
+    } else {
+        exclude_zero_score_issues(&mut issues)
+    };
+    // Vec<String> を Vec<&str> に変換
+    let synced_projects_refs: Vec<&str> = synced_projects.iter().map(|s| s.as_str()).collect();
+
+    match db
+        .save_issues_retaining(
+            workspace.id,
+            &issues,
+            &retained_ids,
+            &synced_projects_refs,
+            &project_keys,
+        )
+        .await
+    {
+        Ok(()) => {
+            // 課題一覧に「最終更新」を表示するため、保存できた時刻を記録する
+            if let Err(e) = db.record_workspace_synced(workspace.id, chrono::Utc::now()).await {
+                error!("Failed to record last synced time of {domain}: {e}");
+            }
+            // 4. 保存成功後、新規・更新チケットをAIジョブとしてキュー投入する（FR-V03-004）。
+            // 無効ワークスペースは呼び出し側（sync_and_notify）で同期対象から除外済みだが、
+            // 念のためジョブ投入は enabled で絞る。
+            if workspace.enabled {
+                enqueue_changed_issues(db, workspace.id, &issues, ctx.existing_updated_map).await;
+
+                // v0.4: 完了課題コーパスの取り込み・コメント差分取得・埋め込みジョブ投入を行う。
+                // すべて sync・UI を阻害しないバックグラウンド処理で、失敗は本体を止めない
+                // （NFR-V04-002 / NFR-V04-005）。レート残量が少ない場合はバックオフして次サイクルへ。
+                sync_corpus_and_embeddings(
+                    db,
+                    &client,
+                    workspace.id,
+                    &project_keys,
+                    &issues,
+                    ctx.existing_updated_map,
+                    last_remaining,
+                )
+                .await;
+            }
+        }
+        Err(e) => {
+            error!("Failed to save issues for workspace {domain}: {e}");
+        }
+    }
+
+    Some(WorkspaceSyncOutcome {
+        issues,
+        notifications,
+        notified_keys,
+        status_changes,
+        disabled: None,
+        due_reminders,
+        score_surges,
+        user_change: None,
+        rate_limited: None,
+        truncated: (!truncated_projects.is_empty()).then(|| FetchTruncated {
+            workspace_id: workspace.id,
+            domain,
+            project_keys: truncated_projects,
+            limit: crate::backlog::ISSUES_FETCH_COUNT,
+        }),
+        changed_issues,
+    })
+}
+
+/// 課題の期限の状態（期限リマインドの分類）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DueState {
+    /// 期限切れ（期限日が昨日以前）
+    Overdue,
+    /// 期限間近（期限日が今日または明日）
+    DueSoon,
+}
+
+/// 期限をリマインドする課題
+#[derive(Debug, Clone, PartialEq)]
+struct DueReminder {
+    workspace_id: i64,
+    issue_key: String,
+    state: DueState,
+}
+
+/// 課題の期限の状態を判定する
+///
+/// 期限は日付単位のため、「24時間以内」は期限日が今日または明日の課題とみなす。
+///
+/// # 引数
+/// * `issue` - 判定する課題
+/// * `today` - 判定の基準日（ローカル日付）
+///
+/// # 戻り値
+/// 期限切れ・期限間近なら状態、期限が無い・先の課題は `None`
+fn due_state(issue: &crate::backlog::Issue, today: chrono::NaiveDate) -> Option<DueState> {
+    let due_date = parse_backlog_date(issue.due_date.as_deref()?)?;
+    match (due_date - today).num_days() {
+        days if days < 0 => Some(DueState::Overdue),
+        0 | 1 => Some(DueState::DueSoon),
+        _ => None,
+    }
+}
+
+/// 期限リマインド通知のタイトルと本文を作る
+///
+/// # 引数
+/// * `lang` - 言語設定（未対応の言語は英語）
+/// * `overdue` - 期限切れの課題数
+/// * `due_soon` - 期限間近の課題数
+///
+/// # 戻り値
+/// `(タイトル, 本文)`
+fn due_reminder_message(lang: &str, overdue: usize, due_soon: usize) -> (String, String) {
+    let mut lines = Vec::new();
+    if due_soon > 0 {
+        let count = due_soon.to_string();
+        lines.push(t("notification.due_reminder.due_soon", lang, &[&count]));
+    }
+    if overdue > 0 {
+        let count = overdue.to_string();
+        lines.push(t("notification.due_reminder.overdue", lang, &[&count]));
+    }
+    (t("notification.due_reminder.title", lang, &[]), lines.join("\n"))
+}
+
+/// 期限リマインドを通知し、通知履歴を記録する
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `db` - データベースクライアント
+/// * `lang` - 言語設定
+/// * `reminders` - リマインドする課題（空でないこと）
+/// * `notified_at` - 通知日時（クールダウンの基準）
+async fn send_due_reminders(
+    app: &AppHandle,
+    db: &DbClient,
+    lang: &str,
+    reminders: &[DueReminder],
+    notified_at: chrono::DateTime<chrono::Utc>,
+) {
+    let overdue = reminders
+        .iter()
+        .filter(|r| r.state == DueState::Overdue)
+        .count();
+    let (title, body) = due_reminder_message(lang, overdue, reminders.len() - overdue);
+
+    info!("Sending due reminder: {body}");
+    match app.notification().builder().title(&title).body(&body).show() {
+        Ok(_) => info!("Due reminder sent successfully"),
+        Err(e) => error!("Failed to send due reminder: {e}"),
+    }
+
+    for reminder in reminders {
+        if let Err(e) = db
+            .record_due_reminder(reminder.workspace_id, &reminder.issue_key, notified_at)
+            .await
+        {
+            warn!("Failed to record due reminder for {}: {e}", reminder.issue_key);
+        }
+    }
+}
+
+/// 前回の同期からスコアが急上昇した課題
+#[derive(Debug, Clone, PartialEq)]
+struct ScoreSurge {
+    workspace_id: i64,
+    issue_key: String,
+    summary: String,
+    /// 前回の同期時のスコア
+    previous_score: i32,
+    /// 今回の同期でのスコア
+    score: i32,
+}
+
+/// 課題のスコアが急上昇したかを判定する
+///
+/// # 引数
+/// * `previous_score` - 前回の同期時のスコア（新規の課題は `None`）
+/// * `score` - 今回の同期でのスコア
+/// * `threshold` - 急上昇とみなす上昇幅（[`SETTING_SCORE_SURGE_THRESHOLD`]）
+///
+/// # 戻り値
+/// 既存の課題で、スコアが `threshold` 以上上がっていれば `true`。新規の課題や
+/// `threshold` が 0 以下の場合は `false`
+fn is_score_surge(previous_score: Option<i32>, score: i32, threshold: i32) -> bool {
+    threshold > 0
+        && previous_score.is_some_and(|previous| score.saturating_sub(previous) >= threshold)
+}
+
+/// スコア急上昇の通知のタイトルと本文を作る
+///
+/// # 引数
+/// * `lang` - 言語設定（未対応の言語は英語）
+/// * `surges` - スコアが急上昇した課題（空でないこと）
+///
+/// # 戻り値
+/// `(タイトル, 本文)`
+fn score_surge_message(lang: &str, surges: &[ScoreSurge]) -> (String, String) {
+    let title = t("notification.score_surge.title", lang, &[]);
+    let body = match surges {
+        [surge] => {
+            let previous = surge.previous_score.to_string();
+            let score = surge.score.to_string();
+            t("notification.score_surge.single", lang, &[&surge.summary, &previous, &score])
+        }
+        _ => t("notification.score_surge.multiple", lang, &[&surges.len().to_string()]),
+    };
+    (title, body)
+}
+
+/// スコアが急上昇した課題を「状況が変化した課題」として通知し、通知履歴を記録する
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `db` - データベースクライアント
+/// * `lang` - 言語設定
+/// * `surges` - スコアが急上昇した課題（空でないこと）
+/// * `notified_at` - 通知日時（クールダウンの基準）
+async fn send_score_surges(
+    app: &AppHandle,
+    db: &DbClient,
+    lang: &str,
+    surges: &[ScoreSurge],
+    notified_at: chrono::DateTime<chrono::Utc>,
+) {
+    let (title, body) = score_surge_message(lang, surges);
+
+    info!("Sending score surge notification: {body}");
+    match app.notification().builder().title(&title).body(&body).show() {
+        Ok(_) => info!("Score surge notification sent successfully"),
+        Err(e) => error!("Failed to send score surge notification: {e}"),
+    }
+
+    for surge in surges {
+        if let Err(e) = db
+            .record_score_surge(surge.workspace_id, &surge.issue_key, notified_at)
+            .await
+        {
+            warn!("Failed to record score surge for {}: {e}", surge.issue_key);
+        }
+    }
+}
+
+/// 認証に失敗したワークスペースを無効化する
+///
+/// 失効したAPIキーで同期のたびに 401 / 403 を出し続けないよう `enabled = false` に更新する。
+/// 呼び出し側では、`get_myself` が認証失敗（[`is_authentication_error`]）になった場合か、
+/// 取得対象の全プロジェクトが認証エラー（[`all_project_fetches_failed_with_auth`]）になった
+/// 場合に限って呼ぶ（一部のプロジェクトの 403 や一時的なエラーでは無効化しない）。
+/// 定期同期と手動同期で同じ条件にそろえる。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+/// * `workspace_id` - ワークスペースID
+/// * `domain` - ワークスペースのドメイン
+/// * `error` - 認証エラー
+///
+/// # 戻り値
+/// 無効化できた場合は `workspace-disabled` イベントのペイロード、DB の更新に失敗した場合は `None`
+pub(crate) async fn disable_workspace_on_auth_failure(
+    db: &DbClient,
+    workspace_id: i64,
+    domain: &str,
+    error: &(dyn std::error::Error + Send + Sync + 'static),
+) -> Option<WorkspaceDisabled> {
+    warn!("Authentication failed for {domain}. Disabling the workspace: {error}");
+    if let Err(e) = db.set_workspace_enabled(workspace_id, false).await {
+        error!("Failed to disable workspace {domain}: {e}");
+        return None;
+    }
+    Some(WorkspaceDisabled {
+        workspace_id,
+        domain: domain.to_string(),
+        reason: WorkspaceDisabledReason::AuthFailed,
+        message: error.to_string(),
+    })
+}
+
+/// 同期した課題のうち、新規・更新分をAIジョブとしてキューに投入する（FR-V03-004）。
+///
+/// 差分検出は同期前のDBスナップショット（`existing_updated_map`）と突き合わせて行う:
+/// - スナップショットに無い課題（初回・新規）→ 投入対象
+/// - スナップショットにあり `updated`（最終更新日時）が変化した課題 → 投入対象
+/// - `updated` が変わっていない課題 → スキップ（再分析しない）
+///
+/// 初回同期（DBに当該ワークスペースの課題が無い状態）では全件が新規として投入される。
+/// 重複した `pending` ジョブの抑止は [`DbClient::enqueue_jobs`] 側で行うため、ここでは
+/// 投入候補のIDを集めて一括で渡す。ジョブ種別は 1行要約+リスク+提案の
+/// [`JOB_TYPE_SUMMARIZE`] を用いる。
+///
+/// 投入失敗は本体（同期）を止めず、エラーログに記録するだけにとどめる（非阻害方針）。
+/// 呼び出し側で無効ワークスペースを除外している前提のため、本関数は enabled を判定しない。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+/// * `workspace_id` - 対象ワークスペースID
 /// * `issues` - 同期して保存した課題のスライス（このワークスペース分）
 /// * `existing_updated_map` - 同期前のDBスナップショット `(workspace_id, issue_id) -> updated`
 pub(crate) async fn enqueue_changed_issues(
@@ -364,6 +2349,174 @@ pub(crate) async fn enqueue_changed_issues(
     }
 }
 
+/// スコア0の課題を取り除く
+///
+/// # 引数
+/// * `issues` - スコアリング済みの課題（スコア0の課題を取り除く）
+///
+/// # 戻り値
+/// 取り除いた課題のID
+pub(crate) fn exclude_zero_score_issues(issues: &mut Vec<crate::backlog::Issue>) -> Vec<i64> {
+    let excluded = issues
+        .iter()
+        .filter(|i| i.relevance_score == 0)
+        .map(|i| i.id)
+        .collect();
+    issues.retain(|i| i.relevance_score != 0);
+    excluded
+}
+
+/// 高スコア（通知しきい値以上）の課題の件数を数える
+///
+/// 通知と件数がずれないよう、課題ごとに所属ワークスペースのしきい値で判定する。
+///
+/// # 引数
+/// * `issues` - 対象の課題
+/// * `thresholds` - 通知しきい値（[`resolve_notification_thresholds`]）
+///
+/// # 戻り値
+/// スコアがしきい値以上の課題の件数
+pub(crate) fn count_high_priority(
+    issues: &[crate::backlog::Issue],
+    thresholds: &NotificationThresholds,
+) -> usize {
+    issues
+        .iter()
+        .filter(|i| i.relevance_score >= thresholds.for_workspace(i.workspace_id))
+        .count()
+}
+
+/// トレイのツールチップ・バッジ・アイコンを高スコア課題の件数で更新する
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `lang` - 表示言語（未対応の言語は英語）
+/// * `high_priority_count` - 高スコア（通知しきい値以上）課題の件数
+pub(crate) fn update_tray_tooltip<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+    lang: &str,
+    high_priority_count: usize,
+) {
+    if let Some(tray) = app.tray_by_id("main") {
+        let tooltip = if high_priority_count > 0 {
+            let count = high_priority_count.to_string();
+            t("tray.tooltip.important", lang, &[&count])
+        } else {
+            t("tray.tooltip", lang, &[])
+        };
+        let _ = tray.set_tooltip(Some(tooltip));
+        update_tray_badge(&tray, high_priority_count);
+        update_tray_icon(app, &tray, TrayIconState::from_count(high_priority_count));
+    }
+}
+
+/// トレイアイコンの見た目（重要課題の有無）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TrayIconState {
+    /// 高スコア課題なし
+    Normal,
+    /// 高スコア課題が1件以上ある
+    Alert,
+}
+
+impl TrayIconState {
+    /// 高スコア課題の件数から状態を決める
+    pub(crate) fn from_count(high_priority_count: usize) -> Self {
+        if high_priority_count > 0 {
+            Self::Alert
+        } else {
+            Self::Normal
+        }
+    }
+
+    /// アイコン画像のリソースパス
+    pub(crate) fn resource_path(self) -> &'static str {
+        match self {
+            Self::Normal => "icons/TrayIconTemplate.png",
+            Self::Alert => "icons/TrayIconAlert.png",
+        }
+    }
+
+    /// macOS でテンプレートアイコン（メニューバーの配色に合わせて単色表示）として扱うか
+    ///
+    /// アラート時は赤い印を残すため、テンプレートとして扱わない。
+    pub(crate) fn is_template(self) -> bool {
+        self == Self::Normal
+    }
+}
+
+/// トレイアイコン画像をリソースから読み込む
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `state` - 読み込むアイコンの状態
+///
+/// # 戻り値
+/// アイコン画像、または読み込みエラー
+pub(crate) fn load_tray_icon<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+    state: TrayIconState,
+) -> Result<tauri::image::Image<'static>, Box<dyn std::error::Error>> {
+    let icon_path = app
+        .path()
+        .resolve(state.resource_path(), tauri::path::BaseDirectory::Resource)?;
+    let rgba = image::open(&icon_path)?.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    Ok(tauri::image::Image::new_owned(rgba.into_raw(), width, height))
+}
+
+/// トレイアイコンを重要課題の有無に応じた画像へ切り替える
+///
+/// 画像を読み込めなければ現在のアイコンを維持する。
+///
+/// # 引数
+/// * `app` - Tauriアプリケーションハンドル
+/// * `tray` - トレイアイコン
+/// * `state` - 切り替え先の状態
+fn update_tray_icon<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+    tray: &tauri::tray::TrayIcon<R>,
+    state: TrayIconState,
+) {
+    let icon = match load_tray_icon(app, state) {
+        Ok(icon) => icon,
+        Err(e) => {
+            warn!("Failed to load tray icon {}: {e}", state.resource_path());
+            return;
+        }
+    };
+    if let Err(e) = tray.set_icon(Some(icon)) {
+        warn!("Failed to update tray icon: {e}");
+        return;
+    }
+    let _ = tray.set_icon_as_template(state.is_template());
+}
+
+/// トレイアイコン横に高スコア課題の件数をバッジ的に表示する
+///
+/// 件数が1以上なら件数をタイトルに、0ならタイトルをクリアする。タイトル表示（`set_title`）は
+/// macOS のメニューバーでのみ有効なため、他のプラットフォームでは何もしない（ツールチップのみ）。
+///
+/// # 引数
+/// * `tray` - トレイアイコン
+/// * `high_priority_count` - 高スコア（通知しきい値以上）課題の件数
+pub(crate) fn update_tray_badge<R: tauri::Runtime>(
+    tray: &tauri::tray::TrayIcon<R>,
+    high_priority_count: usize,
+) {
+    #[cfg(target_os = "macos")]
+    {
+        let title = (high_priority_count > 0).then(|| high_priority_count.to_string());
+        if let Err(e) = tray.set_title(title) {
+            warn!("Failed to update tray title: {e}");
+        }
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = (tray, high_priority_count);
+    }
+}
+
 /// 新規・更新された課題のIDを抽出する（差分検出の共通ロジック）。
 ///
 /// [`enqueue_changed_issues`]（要約ジョブ投入）と v0.4 のコメント差分取得・埋め込みジョブ投入で
@@ -374,48 +2527,368 @@ pub(crate) async fn enqueue_changed_issues(
 /// - `updated` が変わっていない課題 → 非対象（再処理しない）
 ///
 /// # 引数
-/// * `workspace_id` - 対象ワークスペースID
-/// * `issues` - 同期して保存した課題のスライス
-/// * `existing_updated_map` - 同期前のDBスナップショット `(workspace_id, issue_id) -> updated`
+/// * `workspace_id` - 対象ワークスペースID
+/// * `issues` - 同期して保存した課題のスライス
+/// * `existing_updated_map` - 同期前のDBスナップショット `(workspace_id, issue_id) -> updated`
+///
+/// # 戻り値
+/// 新規・更新と判定された課題IDのベクタ
+fn changed_issue_ids(
+    workspace_id: i64,
+    issues: &[crate::backlog::Issue],
+    existing_updated_map: &std::collections::HashMap<(i64, i64), Option<String>>,
+) -> Vec<i64> {
+    issues
+        .iter()
+        .filter(
+            |issue| match existing_updated_map.get(&(workspace_id, issue.id)) {
+                Some(prev_updated) => prev_updated != &issue.updated,
+                None => true,
+            },
+        )
+        .map(|issue| issue.id)
+        .collect()
+}
+
+/// 同期後も課題を既読として扱うかを判定する（既読課題の再通知抑止用）。
+///
+/// 既読でなければ常に `false`。既読でも `unread_on_update` が有効で `updated`（最終更新日時）が
+/// 前回から変化していれば、確認後に動きがあったとみなして未読扱い（`false`）に戻す。
+/// [`DbClient::save_issues`] の `is_read` 引き継ぎと同じ判定にそろえる。
+///
+/// # 引数
+/// * `was_read` - 同期前の既読フラグ
+/// * `unread_on_update` - 更新で未読に戻す設定が有効か
+/// * `old_updated` - 同期前の `updated`
+/// * `new_updated` - 今回取得した `updated`
+///
+/// # 戻り値
+/// 既読のままなら `true`
+fn is_still_read(
+    was_read: bool,
+    unread_on_update: bool,
+    old_updated: Option<&str>,
+    new_updated: Option<&str>,
+) -> bool {
+    was_read && !(unread_on_update && old_updated != new_updated)
+}
+
+/// 設定値から完了課題コーパスの取り込み期間（月数）を解決する（FR-V04-003）。
+///
+/// `settings.corpus_months` を読み、1〜24 にクランプする。未設定・パース失敗・取得失敗は
+/// いずれも [`DEFAULT_CORPUS_MONTHS`] に倒す（バックグラウンド処理を止めないため非阻害）。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+///
+/// # 戻り値
+/// 取り込み期間（月数。1〜24）
+async fn resolve_corpus_months(db: &DbClient) -> i64 {
+    let raw = db
+        .get_setting(SETTING_CORPUS_MONTHS)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|v| v.trim().parse::<i64>().ok())
+        .unwrap_or(DEFAULT_CORPUS_MONTHS);
+    raw.clamp(1, 24)
+}
+
+/// 設定値から課題取得対象の期間（日数）を解決する。
+///
+/// `settings.fetch_period_days` を読み、正の整数ならその日数を返す。未設定・0 以下・パース失敗・
+/// 取得失敗はいずれも `None`（無制限）に倒す。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+///
+/// # 戻り値
+/// 取得期間（日数）。無制限なら `None`
+pub(crate) async fn resolve_fetch_period_days(db: &DbClient) -> Option<i64> {
+    db.get_setting(SETTING_FETCH_PERIOD_DAYS)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|v| v.trim().parse::<i64>().ok())
+        .filter(|days| *days > 0)
+}
+
+/// 設定値から親子課題の扱い（[`ParentIssueMode`]）を解決する。
+///
+/// `settings.parent_issue_mode` を読み、未設定・取得失敗・不正値は [`ParentIssueMode::Off`] に倒す。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+///
+/// # 戻り値
+/// 親子課題の扱い
+pub(crate) async fn resolve_parent_issue_mode(db: &DbClient) -> ParentIssueMode {
+    let value = db
+        .get_setting(crate::scoring::SETTING_PARENT_ISSUE_MODE)
+        .await
+        .ok()
+        .flatten();
+    ParentIssueMode::from_setting(value.as_deref())
+}
+
+/// 設定値からスコアリングの設定（[`ScoringConfig`]）を解決する。
+///
+/// `settings.issue_type_bonus` / `settings.attachment_bonus` / `settings.timezone` を読み、
+/// 未設定・取得失敗・不正値は既定（加点なし・OS のタイムゾーン）に倒す。チームメンバーは
+/// ワークスペースごとの設定にだけ持つ（[`resolve_workspace_scoring_config`]）。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+///
+/// # 戻り値
+/// スコアリングの設定
+pub(crate) async fn resolve_scoring_config(db: &DbClient) -> ScoringConfig {
+    let value = db
+        .get_setting(crate::scoring::SETTING_ISSUE_TYPE_BONUS)
+        .await
+        .ok()
+        .flatten();
+    let attachment_bonus = db
+        .get_setting(crate::scoring::SETTING_ATTACHMENT_BONUS)
+        .await
+        .ok()
+        .flatten();
+    let timezone = db
+        .get_setting(crate::scoring::SETTING_TIMEZONE)
+        .await
+        .ok()
+        .flatten();
+    ScoringConfig::from_setting(value.as_deref())
+        .with_attachment_bonus(attachment_bonus.as_deref())
+        .with_timezone(timezone.as_deref())
+}
+
+/// ワークスペースごとのスコアリング設定を解決する。
+///
+/// `workspaces.scoring_config` があればグローバルの設定（[`resolve_scoring_config`]）を
+/// 上書きし、未設定・取得失敗・不正値ならグローバルの設定をそのまま使う。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+/// * `global` - グローバルのスコアリング設定
+/// * `workspace_id` - ワークスペースID
+///
+/// # 戻り値
+/// ワークスペースに適用するスコアリングの設定
+pub(crate) async fn resolve_workspace_scoring_config(
+    db: &DbClient,
+    global: &ScoringConfig,
+    workspace_id: i64,
+) -> ScoringConfig {
+    let value = db
+        .get_workspace_scoring_config(workspace_id)
+        .await
+        .ok()
+        .flatten();
+    global.clone().with_workspace_override(value.as_deref())
+}
+
+/// 設定値から通知しきい値を解決する。
+///
+/// `settings.notification_threshold` を読み、未設定・取得失敗・不正値は
+/// [`DEFAULT_NOTIFICATION_THRESHOLD`]、範囲外の値はクランプした値を返す。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+///
+/// # 戻り値
+/// 通知しきい値
+pub(crate) async fn resolve_notification_threshold(db: &DbClient) -> i32 {
+    db.get_setting(SETTING_NOTIFICATION_THRESHOLD)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|v| v.trim().parse::<i64>().ok())
+        .map_or(DEFAULT_NOTIFICATION_THRESHOLD, clamp_notification_threshold)
+}
+
+/// 通知しきい値を [`MIN_NOTIFICATION_THRESHOLD`]〜[`MAX_NOTIFICATION_THRESHOLD`] に収める
+fn clamp_notification_threshold(threshold: i64) -> i32 {
+    threshold.clamp(
+        MIN_NOTIFICATION_THRESHOLD as i64,
+        MAX_NOTIFICATION_THRESHOLD as i64,
+    ) as i32
+}
+
+/// ワークスペースごとの通知しきい値
+///
+/// ワークスペースに設定が無ければグローバルの設定（[`resolve_notification_threshold`]）を使う。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NotificationThresholds {
+    /// グローバルの通知しきい値
+    global: i32,
+    /// ワークスペースIDごとの通知しきい値（設定のあるワークスペースのみ）
+    per_workspace: HashMap<i64, i32>,
+}
+
+impl NotificationThresholds {
+    /// ワークスペースの一覧から組み立てる（範囲外の値はクランプする）
+    ///
+    /// # 引数
+    /// * `global` - グローバルの通知しきい値
+    /// * `workspaces` - ワークスペースの一覧
+    fn new(global: i32, workspaces: &[crate::db::Workspace]) -> Self {
+        let per_workspace = workspaces
+            .iter()
+            .filter_map(|w| Some((w.id, clamp_notification_threshold(w.notification_threshold?))))
+            .collect();
+        Self {
+            global,
+            per_workspace,
+        }
+    }
+
+    /// ワークスペースの通知しきい値
+    ///
+    /// # 引数
+    /// * `workspace_id` - ワークスペースID
+    ///
+    /// # 戻り値
+    /// ワークスペースの設定、未設定ならグローバルの通知しきい値
+    pub(crate) fn for_workspace(&self, workspace_id: i64) -> i32 {
+        self.per_workspace
+            .get(&workspace_id)
+            .copied()
+            .unwrap_or(self.global)
+    }
+}
+
+/// ワークスペースごとの通知しきい値を解決する
+///
+/// ワークスペースを取得できない場合は、全ワークスペースでグローバルの設定を使う。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+///
+/// # 戻り値
+/// 通知しきい値
+pub(crate) async fn resolve_notification_thresholds(db: &DbClient) -> NotificationThresholds {
+    let global = resolve_notification_threshold(db).await;
+    let workspaces = db.get_workspaces().await.unwrap_or_default();
+    NotificationThresholds::new(global, &workspaces)
+}
+
+/// 設定値から同期を見送るレート残量のしきい値を解決する。
+///
+/// `settings.rate_limit_skip_threshold` を読み、未設定・取得失敗・不正値は
+/// [`DEFAULT_RATE_LIMIT_SKIP_THRESHOLD`] を返す。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+///
+/// # 戻り値
+/// 同期を見送るしきい値
+pub(crate) async fn resolve_rate_limit_skip_threshold(db: &DbClient) -> i64 {
+    db.get_setting(SETTING_RATE_LIMIT_SKIP_THRESHOLD)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|v| v.trim().parse::<i64>().ok())
+        .unwrap_or(DEFAULT_RATE_LIMIT_SKIP_THRESHOLD)
+}
+
+/// 設定値からスコアの急上昇とみなす上昇幅を解決する。
+///
+/// `settings.score_surge_threshold` を読み、未設定・取得失敗・不正値は
+/// [`DEFAULT_SCORE_SURGE_THRESHOLD`] を返す。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+///
+/// # 戻り値
+/// 急上昇とみなす上昇幅（0 以下なら検知しない）
+pub(crate) async fn resolve_score_surge_threshold(db: &DbClient) -> i32 {
+    db.get_setting(SETTING_SCORE_SURGE_THRESHOLD)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|v| v.trim().parse::<i32>().ok())
+        .unwrap_or(DEFAULT_SCORE_SURGE_THRESHOLD)
+}
+
+/// 設定値から同期全体のタイムアウトを解決する。
+///
+/// `settings.sync_timeout_secs` を読み、未設定・取得失敗・不正値（0 以下を含む）は
+/// [`DEFAULT_SYNC_TIMEOUT_SECS`] を返す。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+///
+/// # 戻り値
+/// 同期全体のタイムアウト
+pub(crate) async fn resolve_sync_timeout(db: &DbClient) -> Duration {
+    let secs = db
+        .get_setting(SETTING_SYNC_TIMEOUT_SECS)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_SYNC_TIMEOUT_SECS);
+    Duration::from_secs(secs)
+}
+
+/// 設定値から定期同期の最短間隔を解決する。
+///
+/// `settings.sync_interval_minutes` を読み、未設定・取得失敗・不正値（0 以下を含む）は
+/// [`DEFAULT_SYNC_INTERVAL_MINUTES`] を返す。
+///
+/// # 引数
+/// * `db` - データベースクライアント
+///
+/// # 戻り値
+/// 定期同期の最短間隔
+pub(crate) async fn resolve_sync_interval(db: &DbClient) -> Duration {
+    let minutes = db
+        .get_setting(SETTING_SYNC_INTERVAL_MINUTES)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|minutes| *minutes > 0)
+        .unwrap_or(DEFAULT_SYNC_INTERVAL_MINUTES);
+    Duration::from_secs(60 * minutes)
+}
+
+/// ワークスペース1件あたりの同期のタイムアウトを返す。
+///
+/// [`WORKSPACE_SYNC_TIMEOUT`] と同期全体のタイムアウトの短い方。
+///
+/// # 引数
+/// * `sync_timeout` - 同期全体のタイムアウト
 ///
 /// # 戻り値
-/// 新規・更新と判定された課題IDのベクタ
-fn changed_issue_ids(
-    workspace_id: i64,
-    issues: &[crate::backlog::Issue],
-    existing_updated_map: &std::collections::HashMap<(i64, i64), Option<String>>,
-) -> Vec<i64> {
-    issues
-        .iter()
-        .filter(
-            |issue| match existing_updated_map.get(&(workspace_id, issue.id)) {
-                Some(prev_updated) => prev_updated != &issue.updated,
-                None => true,
-            },
-        )
-        .map(|issue| issue.id)
-        .collect()
+/// ワークスペース1件あたりのタイムアウト
+pub(crate) fn workspace_sync_timeout(sync_timeout: Duration) -> Duration {
+    WORKSPACE_SYNC_TIMEOUT.min(sync_timeout)
 }
 
-/// 設定値から完了課題コーパスの取り込み期間（月数）を解決する（FR-V04-003）。
+/// 処理が時間内に終わらなければ打ち切ってエラーを返す。
 ///
-/// `settings.corpus_months` を読み、1〜24 にクランプする。未設定・パース失敗・取得失敗は
-/// いずれも [`DEFAULT_CORPUS_MONTHS`] に倒す（バックグラウンド処理を止めないため非阻害）。
+/// 打ち切った処理の future は破棄され、コミット前のトランザクションはロールバックされる。
+/// 課題の保存（[`DbClient::save_issues`]）は全体を1トランザクションで反映するため、保存の途中で
+/// 打ち切っても一部の課題だけが保存されることはない（コミット後に打ち切った場合は全件が残る）。
 ///
 /// # 引数
-/// * `db` - データベースクライアント
+/// * `limit` - 待つ最大時間
+/// * `label` - エラーメッセージに含める処理名
+/// * `task` - 実行する処理
 ///
 /// # 戻り値
-/// 取り込み期間（月数。1〜24）
-async fn resolve_corpus_months(db: &DbClient) -> i64 {
-    let raw = db
-        .get_setting(SETTING_CORPUS_MONTHS)
+/// `task` の結果、またはタイムアウトのエラーメッセージ
+pub(crate) async fn with_timeout<T>(
+    limit: Duration,
+    label: &str,
+    task: impl std::future::Future<Output = Result<T, String>>,
+) -> Result<T, String> {
+    tokio::time::timeout(limit, task)
         .await
-        .ok()
-        .flatten()
-        .and_then(|v| v.trim().parse::<i64>().ok())
-        .unwrap_or(DEFAULT_CORPUS_MONTHS);
-    raw.clamp(1, 24)
+        .unwrap_or_else(|_| Err(format!("{label} timed out after {}s", limit.as_secs())))
 }
 
 /// 完了課題コーパスの取り込み・コメント差分取得・埋め込みジョブ投入を行う（v0.4 / FR-V04-002・003・004）。
@@ -514,6 +2987,107 @@ fn is_rate_backoff(remaining: Option<i64>) -> bool {
     matches!(remaining, Some(r) if r <= RATE_LIMIT_BACKOFF_THRESHOLD)
 }
 
+/// レート残量からワークスペース内のプロジェクト取得の並列数を決める
+///
+/// 残量が取得できない（`None`）場合や [`RATE_LIMIT_BACKOFF_THRESHOLD`] 以下の場合は逐次（1）。
+/// それ以外は残量 [`REMAINING_PER_PROJECT_FETCH`] ごとに1並列とし、
+/// 1〜[`MAX_CONCURRENT_PROJECT_FETCHES`] に収める。
+///
+/// # 引数
+/// * `remaining` - ワークスペースのレート残量
+///
+/// # 戻り値
+/// プロジェクト取得の並列数
+pub(crate) fn adaptive_concurrency(remaining: Option<i64>) -> usize {
+    match remaining {
+        Some(r) if r > RATE_LIMIT_BACKOFF_THRESHOLD => {
+            let slots = (r / REMAINING_PER_PROJECT_FETCH).max(1) as usize;
+            slots.min(MAX_CONCURRENT_PROJECT_FETCHES)
+        }
+        _ => 1,
+    }
+}
+
+/// 1プロジェクト分の課題取得結果（[`BacklogClient::get_issues`] の戻り値）
+pub(crate) type ProjectFetchResult = std::result::Result<
+    (Vec<crate::backlog::Issue>, crate::rate_limit::RateLimitInfo),
+    Box<dyn std::error::Error + Send + Sync>,
+>;
+
+/// ワークスペース内の各プロジェクトの課題を、レート残量に応じた並列数で取得する
+///
+/// 並列数は [`adaptive_concurrency`] で決め、`tokio` のセマフォで同時実行数を制御する。
+/// 取得対象は完了以外のステータス（カスタムステータス含む。プロジェクト単位でキャッシュ）。
+///
+/// # 引数
+/// * `client` - Backlog APIクライアント
+/// * `project_keys` - 取得するプロジェクトキー
+/// * `fetch_period_days` - 課題取得対象の期間（日数。`None` で無制限）
+/// * `remaining` - ワークスペースのレート残量（並列数の決定に使う）
+///
+/// # 戻り値
+/// プロジェクトキーと取得結果の組（`project_keys` と同じ順序）
+pub(crate) async fn fetch_project_issues<'a>(
+    client: &BacklogClient,
+    project_keys: &[&'a str],
+    fetch_period_days: Option<i64>,
+    remaining: Option<i64>,
+) -> Vec<(&'a str, ProjectFetchResult)> {
+    let concurrency = adaptive_concurrency(remaining);
+    debug!("Fetching {} projects with concurrency {concurrency}", project_keys.len());
+    let semaphore = tokio::sync::Semaphore::new(concurrency);
+    let semaphore = &semaphore;
+    let fetches = project_keys.iter().map(|&key| async move {
+        let _permit = semaphore.acquire().await;
+        let target_status_ids = client.get_open_status_ids(key).await;
+        let result = client
+            .get_issues(key, &target_status_ids, fetch_period_days)
+            .await;
+        (key, result)
+    });
+    futures::future::join_all(fetches).await
+}
+
+/// 全プロジェクトの課題取得に失敗したかを判定する
+///
+/// 取得できた0件（成功）と取得の失敗を区別するため、[`fetch_project_issues`] で成功した
+/// プロジェクトの有無で判定する。全て失敗した同期結果は保存せず、前回の課題をそのまま残す。
+/// プロジェクトが一つも設定されていない場合は失敗ではない（保存時に課題を片付ける）。
+///
+/// # 引数
+/// * `project_keys` - 取得対象のプロジェクトキー
+/// * `synced_project_keys` - 取得に成功したプロジェクトキー
+///
+/// # 戻り値
+/// 取得対象があり、1件も取得に成功しなかった場合は `true`
+pub(crate) fn all_project_fetches_failed(
+    project_keys: &[&str],
+    synced_project_keys: &[String],
+) -> bool {
+    !project_keys.is_empty() && synced_project_keys.is_empty()
+}
+
+/// 取得対象の全プロジェクトが認証エラーで失敗したかを判定する
+///
+/// 1つでも取得に成功したか、認証以外の理由で失敗したプロジェクトがあれば、APIキー自体は
+/// 有効とみなしてワークスペースを無効化しない。
+///
+/// # 引数
+/// * `project_keys` - 取得対象のプロジェクトキー
+/// * `auth_failed` - 認証エラーで取得に失敗したプロジェクトキーとエラー
+///
+/// # 戻り値
+/// 全プロジェクトが認証エラーなら、無効化の理由として通知するエラー（最初のプロジェクトのもの）
+pub(crate) fn all_project_fetches_failed_with_auth<'a, E>(
+    project_keys: &[&str],
+    auth_failed: &'a [(String, E)],
+) -> Option<&'a (String, E)> {
+    if project_keys.is_empty() || auth_failed.len() < project_keys.len() {
+        return None;
+    }
+    auth_failed.first()
+}
+
 /// コーパス取得の `updatedSince`（`yyyy-MM-dd`）を月数から算出する（FR-V04-003）。
 ///
 /// 現在日時から概算で `months * 30` 日さかのぼった日付を `yyyy-MM-dd` で返す（Backlog の
@@ -998,6 +3572,10 @@ mod tests {
             assignee: None,
             due_date: None,
             updated: updated.map(|s| s.to_string()),
+            parent_issue_id: None,
+            milestone: None,
+            category: None,
+            attachments: None,
             created: None,
             relevance_score: 0,
             workspace_id: 1,
@@ -1008,6 +3586,11 @@ mod tests {
             ai_processed_at: None,
             is_corpus_only: false,
             embedding_ready: false,
+            is_read: false,
+            comment_count: None,
+            watching: false,
+            commented_by_me: None,
+            comment_mentions: None,
         }
     }
 
@@ -1079,6 +3662,98 @@ mod tests {
         assert_eq!(resolve_corpus_months(&db).await, DEFAULT_CORPUS_MONTHS);
     }
 
+    #[tokio::test]
+    async fn resolve_fetch_period_days_defaults_to_unlimited() {
+        let db = memory_db().await;
+
+        // 未設定 → 無制限。
+        assert_eq!(resolve_fetch_period_days(&db).await, None);
+
+        db.save_setting(SETTING_FETCH_PERIOD_DAYS, "30").await.unwrap();
+        assert_eq!(resolve_fetch_period_days(&db).await, Some(30));
+
+        // 0 以下・パース不能は無制限。
+        db.save_setting(SETTING_FETCH_PERIOD_DAYS, "0").await.unwrap();
+        assert_eq!(resolve_fetch_period_days(&db).await, None);
+        db.save_setting(SETTING_FETCH_PERIOD_DAYS, "abc").await.unwrap();
+        assert_eq!(resolve_fetch_period_days(&db).await, None);
+    }
+
+    #[tokio::test]
+    async fn notification_threshold_is_clamped_and_changes_high_priority_count() {
+        let db = memory_db().await;
+        let issues: Vec<_> = [50, 80, 120]
+            .into_iter()
+            .enumerate()
+            .map(|(i, score)| {
+                let mut issue = issue(i as i64 + 1, None);
+                issue.relevance_score = score;
+                issue
+            })
+            .collect();
+
+        // 未設定 → 既定の 80。
+        assert_eq!(resolve_notification_threshold(&db).await, DEFAULT_NOTIFICATION_THRESHOLD);
+        let thresholds = resolve_notification_thresholds(&db).await;
+        assert_eq!(count_high_priority(&issues, &thresholds), 2);
+
+        // しきい値を下げると対象が増え、上げると減る。
+        db.save_setting(SETTING_NOTIFICATION_THRESHOLD, "50").await.unwrap();
+        let thresholds = resolve_notification_thresholds(&db).await;
+        assert_eq!(count_high_priority(&issues, &thresholds), 3);
+        db.save_setting(SETTING_NOTIFICATION_THRESHOLD, "100").await.unwrap();
+        let thresholds = resolve_notification_thresholds(&db).await;
+        assert_eq!(count_high_priority(&issues, &thresholds), 1);
+
+        // 0 以下・極端な値はクランプ、パース不能は既定値。
+        db.save_setting(SETTING_NOTIFICATION_THRESHOLD, "-5").await.unwrap();
+        assert_eq!(resolve_notification_threshold(&db).await, MIN_NOTIFICATION_THRESHOLD);
+        db.save_setting(SETTING_NOTIFICATION_THRESHOLD, "99999999999").await.unwrap();
+        assert_eq!(resolve_notification_threshold(&db).await, MAX_NOTIFICATION_THRESHOLD);
+        db.save_setting(SETTING_NOTIFICATION_THRESHOLD, "abc").await.unwrap();
+        assert_eq!(resolve_notification_threshold(&db).await, DEFAULT_NOTIFICATION_THRESHOLD);
+    }
+
+    #[test]
+    fn exclude_zero_score_issues_returns_removed_ids() {
+        let mut issues: Vec<_> = [0, 30, 0]
+            .into_iter()
+            .enumerate()
+            .map(|(i, score)| {
+                let mut issue = issue(i as i64 + 1, None);
+                issue.relevance_score = score;
+                issue
+            })
+            .collect();
+
+        assert_eq!(exclude_zero_score_issues(&mut issues), [1, 3]);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, 2);
+    }
+
+    #[test]
+    fn tray_icon_switches_to_alert_only_with_high_priority_issues() {
+        assert_eq!(TrayIconState::from_count(0), TrayIconState::Normal);
+        assert_eq!(TrayIconState::from_count(1), TrayIconState::Alert);
+        // 通常時はメニューバーの配色に合わせ、アラート時は赤い印を残す
+        assert!(TrayIconState::Normal.is_template());
+        assert!(!TrayIconState::Alert.is_template());
+        assert_ne!(TrayIconState::Normal.resource_path(), TrayIconState::Alert.resource_path());
+    }
+
+    #[test]
+    fn network_state_reports_only_transitions() {
+        let state = NetworkState::new();
+        // オンラインのまま → 変化なし
+        assert!(!state.update(true));
+        // オフラインへ切り替わり → 一度だけ変化あり
+        assert!(state.update(false));
+        assert!(!state.update(false));
+        // 復帰 → 変化あり（以降の同期は通常どおり実行される）
+        assert!(state.update(true));
+        assert!(!state.update(true));
+    }
+
     /// テスト用のインメモリ DB を作る（マイグレーション適用済み）。
     async fn memory_db() -> DbClient {
         use sqlx::sqlite::SqliteConnectOptions;
@@ -1089,6 +3764,370 @@ mod tests {
         db
     }
 
+    /// テスト用のワークスペース入力（プロジェクト `PROJ` を対象にした有効なワークスペース）を作る。
+    fn workspace_input(domain: &str) -> crate::db::WorkspaceInput {
+        crate::db::WorkspaceInput {
+            domain: domain.to_string(),
+            api_key: "key".to_string(),
+            project_keys: "PROJ".to_string(),
+            user_id: None,
+            user_name: None,
+            enabled: true,
+            api_limit: None,
+            api_remaining: None,
+            api_reset: None,
+            label: None,
+        }
+    }
+
+    #[test]
+    fn diff_issue_snapshots_classifies_added_updated_removed() {
+        let snapshot = |entries: &[((i64, i64), Option<&str>, i32)]| -> IssueSnapshot {
+            entries
+                .iter()
+                .map(|(key, updated, score)| (*key, (updated.map(str::to_string), *score)))
+                .collect()
+        };
+        let before = snapshot(&[
+            ((1, 10), Some("2026-01-01T00:00:00Z"), 50),
+            ((1, 11), Some("2026-01-01T00:00:00Z"), 50),
+            ((1, 12), Some("2026-01-01T00:00:00Z"), 50),
+            ((2, 10), None, 0),
+        ]);
+        let after = snapshot(&[
+            // 変化なし
+            ((1, 10), Some("2026-01-01T00:00:00Z"), 50),
+            // 更新日時が変わった
+            ((1, 11), Some("2026-01-02T00:00:00Z"), 50),
+            // スコアだけ変わった
+            ((1, 12), Some("2026-01-01T00:00:00Z"), 80),
+            // 別ワークスペースの同じ課題IDは別の課題
+            ((2, 11), None, 0),
+        ]);
+        let issue_ref = |workspace_id, issue_id| IssueRef {
+            workspace_id,
+            issue_id,
+        };
+
+        let delta = diff_issue_snapshots(&before, &after);
+        assert_eq!(delta.added, vec![issue_ref(2, 11)]);
+        assert_eq!(delta.updated, vec![issue_ref(1, 11), issue_ref(1, 12)]);
+        assert_eq!(delta.removed, vec![issue_ref(2, 10)]);
+        assert!(diff_issue_snapshots(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn rate_limited_only_when_low_remaining_before_reset() {
+        let now = chrono::Utc::now();
+        let reset = (now + chrono::Duration::minutes(30)).timestamp().to_string();
+        let past_reset = (now - chrono::Duration::minutes(1)).timestamp().to_string();
+        let detect = |remaining: Option<i64>, reset: Option<&str>| {
+            detect_rate_limited(1, "example.backlog.com", remaining, reset, 10, now)
+        };
+
+        let rate_limited = detect(Some(9), Some(reset.as_str())).unwrap();
+        assert_eq!(rate_limited.remaining, 9);
+        assert_eq!(
+            crate::rate_limit::parse_reset(&rate_limited.reset).map(|dt| dt.timestamp()),
+            reset.parse().ok()
+        );
+        // しきい値ちょうどは同期する
+        assert!(detect(Some(10), Some(reset.as_str())).is_none());
+        // リセット時刻を過ぎていれば再開する
+        assert!(detect(Some(0), Some(past_reset.as_str())).is_none());
+        // 残量・リセット時刻が不明なら通常通り同期する
+        assert!(detect(None, Some(reset.as_str())).is_none());
+        assert!(detect(Some(0), None).is_none());
+    }
+
+    #[test]
+    fn rate_limit_updated_normalizes_reset_and_restores_after_reset() {
+        let now = chrono::Utc::now();
+        let reset = now + chrono::Duration::minutes(30);
+        let info = |reset: chrono::DateTime<chrono::Utc>| crate::rate_limit::RateLimitInfo {
+            limit: Some(150),
+            remaining: Some(120),
+            reset: Some(reset.timestamp().to_string()),
+        };
+
+        let updated = rate_limit_updated(1, info(reset), now);
+        assert_eq!(updated.workspace_id, 1);
+        assert_eq!(updated.rate_limit.limit, Some(150));
+        assert_eq!(updated.rate_limit.remaining, Some(120));
+        assert_eq!(
+            updated.rate_limit.reset.as_deref(),
+            Some(reset.to_rfc3339_opts(chrono::SecondsFormat::Secs, true).as_str())
+        );
+
+        // リセット時刻を過ぎていれば満タンとして送る
+        let updated = rate_limit_updated(1, info(now - chrono::Duration::minutes(1)), now);
+        assert_eq!(updated.rate_limit.remaining, Some(150));
+    }
+
+    #[tokio::test]
+    async fn with_timeout_aborts_slow_task_without_blocking_others() {
+        let limit = Duration::from_millis(20);
+        let task = |delay: Duration, value: usize| async move {
+            tokio::time::sleep(delay).await;
+            Ok::<_, String>(value)
+        };
+        let tasks = [(Duration::from_secs(60), 1), (Duration::ZERO, 2)];
+        let results: Vec<_> = futures::stream::iter(tasks)
+            .map(|(delay, value)| with_timeout(limit, "Workspace sync", task(delay, value)))
+            .buffered(MAX_CONCURRENT_WORKSPACE_SYNCS)
+            .collect()
+            .await;
+
+        // 遅いワークスペースだけ打ち切られ、他の結果は得られる
+        assert!(results[0].as_ref().unwrap_err().starts_with("Workspace sync timed out"));
+        assert_eq!(results[1], Ok(2));
+    }
+
+    #[tokio::test]
+    async fn workspace_scoring_config_overrides_global_per_workspace() {
+        let db = memory_db().await;
+        for domain in ["ws1.example.com", "ws2.example.com"] {
+            db.save_workspace(workspace_input(domain)).await.unwrap();
+        }
+        db.save_setting(crate::scoring::SETTING_ATTACHMENT_BONUS, "10").await.unwrap();
+        db.save_workspace_scoring_config(1, Some(r#"{"attachment_bonus": 30}"#))
+            .await
+            .unwrap();
+        let global = resolve_scoring_config(&db).await;
+
+        // 設定のあるワークスペースだけ上書きし、他はグローバルの設定を使う
+        let config = resolve_workspace_scoring_config(&db, &global, 1).await;
+        assert_eq!(config.attachment_bonus, 30);
+        let config = resolve_workspace_scoring_config(&db, &global, 2).await;
+        assert_eq!(config, global);
+
+        // 設定を消すとグローバルの設定に戻る
+        db.save_workspace_scoring_config(1, None).await.unwrap();
+        assert_eq!(resolve_workspace_scoring_config(&db, &global, 1).await, global);
+        // 存在しないワークスペースへの保存はエラー
+        assert!(db.save_workspace_scoring_config(99, None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn sync_progress_counts_up_in_completion_order() {
+        let tracker = SyncProgressTracker::new(3);
+        let events = std::sync::Mutex::new(Vec::new());
+        let (tracker_ref, events_ref) = (&tracker, &events);
+        // 同期と同じく2並列で処理し、後から始めた ws2 が ws1 より先に終わる。
+        // 順序は時間ではなくチャネルで決める（ws1 の開始 → ws2 の開始・終了 → ws1 の終了）
+        let (ws1_started_tx, ws1_started_rx) = futures::channel::oneshot::channel::<()>();
+        let (ws2_finished_tx, ws2_finished_rx) = futures::channel::oneshot::channel::<()>();
+        let workspaces = [
+            ("ws1", None, Some(ws1_started_tx), Some(ws2_finished_rx), None),
+            ("ws2", Some(ws1_started_rx), None, None, Some(ws2_finished_tx)),
+            ("ws3", None, None, None, None),
+        ];
+        futures::stream::iter(workspaces)
+            .map(|(label, wait_start, notify_start, wait_finish, notify_finish)| async move {
+                if let Some(rx) = wait_start {
+                    rx.await.unwrap();
+                }
+                events_ref.lock().unwrap().push(tracker_ref.started(label));
+                if let Some(tx) = notify_start {
+                    tx.send(()).unwrap();
+                }
+                if let Some(rx) = wait_finish {
+                    rx.await.unwrap();
+                }
+                events_ref.lock().unwrap().push(tracker_ref.finished(label));
+                if let Some(tx) = notify_finish {
+                    tx.send(()).unwrap();
+                }
+            })
+            .buffered(2)
+            .collect::<Vec<()>>()
+            .await;
+
+        let events = events.into_inner().unwrap();
+        let summary: Vec<_> = events
+            .iter()
+            .map(|e| (e.workspace_label.as_str(), e.finished, e.current))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("ws1", false, 0),
+                ("ws2", false, 0),
+                ("ws2", true, 1),
+                ("ws1", true, 2),
+                ("ws3", false, 2),
+                ("ws3", true, 3),
+            ]
+        );
+        assert!(events.iter().all(|e| e.total == 3));
+    }
+
+    #[tokio::test]
+    async fn snoozed_workspaces_are_skipped_until_the_deadline() {
+        use chrono::TimeZone;
+        let db = memory_db().await;
+        for domain in ["ws1.example.com", "ws2.example.com"] {
+            db.save_workspace(workspace_input(domain)).await.unwrap();
+        }
+        db.save_issues(1, &[issue(1, None)], &["PROJ"], &["PROJ"]).await.unwrap();
+        let now = chrono::Utc.with_ymd_and_hms(2026, 10, 16, 9, 0, 0).unwrap();
+        let targets = |workspaces: &[crate::db::Workspace], now| {
+            workspaces
+                .iter()
+                .filter(|w| is_scheduled_sync_target(w, now))
+                .map(|w| w.id)
+                .collect::<Vec<_>>()
+        };
+
+        db.save_workspace_snoozed_until(Some(1), Some("2026-10-16T18:00:00+09:00"))
+            .await
+            .unwrap();
+        let workspaces = db.get_workspaces().await.unwrap();
+        assert_eq!(targets(&workspaces, now), vec![2]);
+        // 期限を過ぎれば同期を再開する
+        assert_eq!(targets(&workspaces, now + chrono::Duration::hours(1)), vec![1, 2]);
+        // スヌーズしても課題は残す
+        assert_eq!(db.get_issues().await.unwrap().len(), 1);
+
+        // 全体のスヌーズと解除
+        db.save_workspace_snoozed_until(None, Some("2026-10-17T00:00:00+00:00"))
+            .await
+            .unwrap();
+        assert!(targets(&db.get_workspaces().await.unwrap(), now).is_empty());
+        db.save_workspace_snoozed_until(Some(2), None).await.unwrap();
+        assert_eq!(targets(&db.get_workspaces().await.unwrap(), now), vec![2]);
+        assert!(db.save_workspace_snoozed_until(Some(99), None).await.is_err());
+        // 無効なワークスペースはスヌーズに関わらず同期しない
+        db.save_workspace_snoozed_until(None, None).await.unwrap();
+        db.set_workspace_enabled(1, false).await.unwrap();
+        assert_eq!(targets(&db.get_workspaces().await.unwrap(), now), vec![2]);
+    }
+
+    #[tokio::test]
+    async fn notification_threshold_can_be_set_per_workspace() {
+        let db = memory_db().await;
+        for domain in ["ws1.example.com", "ws2.example.com"] {
+            db.save_workspace(workspace_input(domain)).await.unwrap();
+        }
+        db.save_setting(SETTING_NOTIFICATION_THRESHOLD, "100").await.unwrap();
+        db.save_workspace_notification_threshold(1, Some(50))
+            .await
+            .unwrap();
+        let thresholds = resolve_notification_thresholds(&db).await;
+        assert_eq!(thresholds.for_workspace(1), 50);
+        assert_eq!(thresholds.for_workspace(2), 100);
+
+        // 件数はそれぞれのワークスペースのしきい値で数える
+        let issues: Vec<_> = [(1, 60), (1, 40), (2, 60), (2, 120)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (workspace_id, score))| {
+                let mut issue = issue(i as i64 + 1, None);
+                issue.workspace_id = workspace_id;
+                issue.relevance_score = score;
+                issue
+            })
+            .collect();
+        assert_eq!(count_high_priority(&issues, &thresholds), 2);
+
+        // 範囲外の値はクランプし、未設定に戻すとグローバルの設定を使う
+        db.save_workspace_notification_threshold(2, Some(-5))
+            .await
+            .unwrap();
+        db.save_workspace_notification_threshold(1, None)
+            .await
+            .unwrap();
+        let thresholds = resolve_notification_thresholds(&db).await;
+        assert_eq!(thresholds.for_workspace(1), 100);
+        assert_eq!(thresholds.for_workspace(2), MIN_NOTIFICATION_THRESHOLD);
+        assert!(db.save_workspace_notification_threshold(99, None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn sync_timeout_defaults_and_caps_workspace_timeout() {
+        let db = memory_db().await;
+        let default_timeout = Duration::from_secs(DEFAULT_SYNC_TIMEOUT_SECS);
+        assert_eq!(resolve_sync_timeout(&db).await, default_timeout);
+        assert_eq!(workspace_sync_timeout(default_timeout), WORKSPACE_SYNC_TIMEOUT);
+
+        db.save_setting(SETTING_SYNC_TIMEOUT_SECS, "60").await.unwrap();
+        let timeout = resolve_sync_timeout(&db).await;
+        assert_eq!(timeout, Duration::from_secs(60));
+        // 全体のタイムアウトの方が短ければそちらに合わせる
+        assert_eq!(workspace_sync_timeout(timeout), timeout);
+
+        // 0 やパース不能な値は既定値
+        for value in ["0", "-1", "abc"] {
+            db.save_setting(SETTING_SYNC_TIMEOUT_SECS, value).await.unwrap();
+            assert_eq!(resolve_sync_timeout(&db).await, default_timeout);
+        }
+    }
+
+    #[test]
+    fn scheduler_restarts_for_auto_sync_and_interval_settings() {
+        assert!(restarts_scheduler(SETTING_AUTO_SYNC_ENABLED));
+        assert!(restarts_scheduler(SETTING_SYNC_INTERVAL_MINUTES));
+        assert!(!restarts_scheduler(SETTING_SYNC_TIMEOUT_SECS));
+        assert!(!restarts_scheduler("language"));
+    }
+
+    #[tokio::test]
+    async fn sync_interval_defaults_to_the_shortest_step() {
+        let db = memory_db().await;
+        let default_interval = Duration::from_secs(60 * DEFAULT_SYNC_INTERVAL_MINUTES);
+        assert_eq!(default_interval, SYNC_INTERVAL_STEPS[0]);
+        assert_eq!(resolve_sync_interval(&db).await, default_interval);
+
+        db.save_setting(SETTING_SYNC_INTERVAL_MINUTES, "15").await.unwrap();
+        assert_eq!(resolve_sync_interval(&db).await, Duration::from_secs(60 * 15));
+
+        // 0 やパース不能な値は既定値
+        for value in ["0", "-1", "abc"] {
+            db.save_setting(SETTING_SYNC_INTERVAL_MINUTES, value).await.unwrap();
+            assert_eq!(resolve_sync_interval(&db).await, default_interval);
+        }
+    }
+
+    #[test]
+    fn all_project_fetches_failed_distinguishes_empty_results_from_failures() {
+        let synced = |keys: &[&str]| keys.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+        // 取得に成功したプロジェクトがあれば、0件でも失敗ではない
+        assert!(!all_project_fetches_failed(&["PROJ", "OTHER"], &synced(&["OTHER"])));
+        assert!(all_project_fetches_failed(&["PROJ", "OTHER"], &synced(&[])));
+        // プロジェクト未設定は失敗ではない
+        assert!(!all_project_fetches_failed(&[], &synced(&[])));
+    }
+
+    #[test]
+    fn workspace_is_disabled_only_when_every_project_fails_with_auth_error() {
+        let failed = |keys: &[&str]| keys.iter().map(|k| (k.to_string(), ())).collect::<Vec<_>>();
+        // 一部のプロジェクトだけの 403 では無効化しない
+        assert!(all_project_fetches_failed_with_auth(&["PROJ", "OTHER"], &failed(&["PROJ"]))
+            .is_none());
+        let all_failed = failed(&["PROJ", "OTHER"]);
+        let disabled = all_project_fetches_failed_with_auth(&["PROJ", "OTHER"], &all_failed);
+        assert_eq!(disabled.map(|(key, _)| key.as_str()), Some("PROJ"));
+        // プロジェクト未設定は無効化しない
+        assert!(all_project_fetches_failed_with_auth(&[], &failed(&[])).is_none());
+    }
+
+    #[tokio::test]
+    async fn auto_sync_enabled_defaults_to_true() {
+        let db = memory_db().await;
+        assert!(is_auto_sync_enabled(&db).await);
+        db.save_setting_typed(SETTING_AUTO_SYNC_ENABLED, &false)
+            .await
+            .unwrap();
+        assert!(!is_auto_sync_enabled(&db).await);
+        db.save_setting_typed(SETTING_AUTO_SYNC_ENABLED, &true)
+            .await
+            .unwrap();
+        assert!(is_auto_sync_enabled(&db).await);
+        // 不正値は有効扱い
+        db.save_setting(SETTING_AUTO_SYNC_ENABLED, "off").await.unwrap();
+        assert!(is_auto_sync_enabled(&db).await);
+    }
+
     #[tokio::test]
     async fn is_ai_enabled_only_true_string() {
         let db = memory_db().await;
@@ -1167,4 +4206,234 @@ mod tests {
         .unwrap();
         assert!(!period_report_is_due(&db, ws, REPORT_TYPE_WEEKLY, &week_key, lang).await);
     }
+
+    #[test]
+    fn detect_user_change_compares_stored_user_id() {
+        let me = crate::backlog::User {
+            id: 2,
+            name: "佐藤".to_string(),
+            mail_address: None,
+            role_type: None,
+        };
+        // 同じユーザー・保存済みのユーザーが無い場合は変化なし
+        assert_eq!(detect_user_change(1, "a.backlog.com", Some(2), Some("佐藤"), &me), None);
+        assert_eq!(detect_user_change(1, "a.backlog.com", None, None, &me), None);
+
+        let change = detect_user_change(1, "a.backlog.com", Some(1), Some("山田"), &me).unwrap();
+        assert_eq!(change.previous_user_id, 1);
+        assert_eq!(change.previous_user_name.as_deref(), Some("山田"));
+        assert_eq!(change.current_user_id, 2);
+        assert_eq!(change.current_user_name, "佐藤");
+    }
+
+    #[test]
+    fn adaptive_interval_extends_while_unchanged_and_resets() {
+        let minutes = |interval: &AdaptiveInterval| interval.current().as_secs() / 60;
+        let mut interval = AdaptiveInterval::default();
+        assert_eq!(minutes(&interval), 5);
+
+        // 変化の無い同期が続くと 5分→10分→30分 と延び、上限で止まる
+        let mut history = Vec::new();
+        for _ in 0..6 {
+            interval.record(false);
+            history.push(minutes(&interval));
+        }
+        assert_eq!(history, [5, 10, 10, 30, 30, 30]);
+
+        // 変化があれば最短に戻る
+        interval.record(true);
+        assert_eq!(minutes(&interval), 5);
+
+        // 手動同期・ワークスペース追加のリセットでも最短に戻る
+        for _ in 0..4 {
+            interval.record(false);
+        }
+        assert_eq!(minutes(&interval), 30);
+        interval.reset();
+        assert_eq!(interval, AdaptiveInterval::default());
+    }
+
+    #[test]
+    fn adaptive_concurrency_scales_with_remaining() {
+        // 残量不明・閾値以下は逐次
+        assert_eq!(adaptive_concurrency(None), 1);
+        assert_eq!(adaptive_concurrency(Some(0)), 1);
+        assert_eq!(adaptive_concurrency(Some(RATE_LIMIT_BACKOFF_THRESHOLD)), 1);
+        // 閾値を超えれば残量に応じて増え、上限で頭打ち
+        assert_eq!(adaptive_concurrency(Some(RATE_LIMIT_BACKOFF_THRESHOLD + 1)), 1);
+        assert_eq!(adaptive_concurrency(Some(250)), 2);
+        assert_eq!(adaptive_concurrency(Some(399)), 3);
+        assert_eq!(adaptive_concurrency(Some(150_000)), MAX_CONCURRENT_PROJECT_FETCHES);
+    }
+
+    #[test]
+    fn is_still_read_resets_only_on_update_when_enabled() {
+        let old = Some("2026-06-10T00:00:00Z");
+        let new = Some("2026-06-11T00:00:00Z");
+        // 未読は常に未読
+        assert!(!is_still_read(false, true, old, old));
+        // 既読・更新なし → 既読のまま（再通知しない）
+        assert!(is_still_read(true, true, old, old));
+        // 既読・更新あり・未読に戻す設定 → 未読扱い
+        assert!(!is_still_read(true, true, old, new));
+        // 既読・更新あり・既読維持の設定 → 既読のまま
+        assert!(is_still_read(true, false, old, new));
+    }
+
+    #[test]
+    fn due_state_distinguishes_overdue_and_due_soon() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 6, 10).unwrap();
+        let due = |value: Option<&str>| {
+            let mut i = issue(1, None);
+            i.due_date = value.map(str::to_string);
+            due_state(&i, today)
+        };
+        assert_eq!(due(Some("2026-06-09T00:00:00Z")), Some(DueState::Overdue));
+        assert_eq!(due(Some("2026-06-10T00:00:00Z")), Some(DueState::DueSoon));
+        assert_eq!(due(Some("2026-06-11")), Some(DueState::DueSoon));
+        assert_eq!(due(Some("2026-06-12T00:00:00Z")), None);
+        assert_eq!(due(None), None);
+
+        let (_, body) = due_reminder_message("ja", 1, 2);
+        assert_eq!(body, "期限が近い課題が2件あります。\n期限切れの課題が1件あります。");
+        let (_, body) = due_reminder_message("en", 0, 3);
+        assert_eq!(body, "3 issues are due soon.");
+    }
+
+    #[test]
+    fn is_score_surge_detects_only_large_rises_of_existing_issues() {
+        assert!(is_score_surge(Some(40), 90, 50));
+        assert!(is_score_surge(Some(40), 150, 50));
+        assert!(!is_score_surge(Some(41), 90, 50));
+        // スコアが下がった・新規の課題は対象外
+        assert!(!is_score_surge(Some(150), 40, 50));
+        assert!(!is_score_surge(None, 150, 50));
+        // 0 以下のしきい値では通知しない
+        assert!(!is_score_surge(Some(0), 150, 0));
+        // 差の計算はオーバーフローしない
+        assert!(is_score_surge(Some(i32::MIN), i32::MAX, 50));
+
+        let surge = |issue_key: &str| ScoreSurge {
+            workspace_id: 1,
+            issue_key: issue_key.to_string(),
+            summary: "障害対応".to_string(),
+            previous_score: 30,
+            score: 120,
+        };
+        let (title, body) = score_surge_message("ja", &[surge("PROJ-1")]);
+        assert_eq!(title, "ProjectLens 状況が変化した課題");
+        assert_eq!(body, "スコアが急上昇しました: 障害対応 (30 → 120)");
+        let (_, body) = score_surge_message("en", &[surge("PROJ-1"), surge("PROJ-2")]);
+        assert_eq!(body, "Scores of 2 issues jumped.");
+    }
+
+    #[tokio::test]
+    async fn score_surge_threshold_falls_back_to_default() {
+        let db = memory_db().await;
+        assert_eq!(resolve_score_surge_threshold(&db).await, DEFAULT_SCORE_SURGE_THRESHOLD);
+        db.save_setting(SETTING_SCORE_SURGE_THRESHOLD, "80").await.unwrap();
+        assert_eq!(resolve_score_surge_threshold(&db).await, 80);
+        db.save_setting(SETTING_SCORE_SURGE_THRESHOLD, "abc").await.unwrap();
+        assert_eq!(resolve_score_surge_threshold(&db).await, DEFAULT_SCORE_SURGE_THRESHOLD);
+    }
+
+    #[test]
+    fn status_change_detects_transitions_including_none() {
+        let with_status = |name: Option<&str>| {
+            let mut i = issue(1, None);
+            i.status = name.map(|n| crate::backlog::Status {
+                id: 1,
+                name: n.to_string(),
+            });
+            i
+        };
+        let old = |name: Option<&str>| name.map(|n| n.to_string());
+
+        // 新規課題（同期前に存在しない）は変化として扱わない
+        assert_eq!(status_change(&with_status(Some("処理中")), None), None);
+        // 同じステータスは変化なし（None 同士も含む）
+        assert_eq!(status_change(&with_status(Some("処理中")), Some(&old(Some("処理中")))), None);
+        assert_eq!(status_change(&with_status(None), Some(&old(None))), None);
+
+        // 値 → 値
+        let change =
+            status_change(&with_status(Some("処理済み")), Some(&old(Some("処理中")))).unwrap();
+        assert_eq!(change.issue_key, "PROJ-1");
+        assert_eq!(change.old_status.as_deref(), Some("処理中"));
+        assert_eq!(change.new_status.as_deref(), Some("処理済み"));
+        // None → 値、値 → None
+        let change = status_change(&with_status(Some("未対応")), Some(&old(None))).unwrap();
+        assert_eq!((change.old_status, change.new_status.as_deref()), (None, Some("未対応")));
+        let change = status_change(&with_status(None), Some(&old(Some("未対応")))).unwrap();
+        assert_eq!((change.old_status.as_deref(), change.new_status), (Some("未対応"), None));
+        assert!(!change.closed);
+    }
+
+    #[tokio::test]
+    async fn comment_counts_are_refetched_only_for_changed_issues() {
+        let db = memory_db().await;
+        db.save_workspace(workspace_input("ws1.example.com")).await.unwrap();
+        let mut stored = issue(1, Some("2026-10-01T00:00:00Z"));
+        stored.comment_count = Some(7);
+        db.save_issues(1, &[stored], &["PROJ"], &["PROJ"]).await.unwrap();
+        let existing_updated_map: HashMap<(i64, i64), Option<String>> =
+            [((1, 1), Some("2026-10-01T00:00:00Z".to_string()))].into();
+
+        // 更新の無い課題は前回のコメント数を引き継ぎ、新規・更新された課題だけ取得し直す
+        let mut checks = CommentCountChecks::load(&db, 1, &existing_updated_map, Some(1000)).await;
+        let mut unchanged = issue(1, Some("2026-10-01T00:00:00Z"));
+        assert!(!checks.should_fetch(&mut unchanged));
+        assert_eq!(unchanged.comment_count, Some(7));
+        let mut updated = issue(1, Some("2026-10-02T00:00:00Z"));
+        assert!(checks.should_fetch(&mut updated));
+        let mut added = issue(2, None);
+        assert!(checks.should_fetch(&mut added));
+
+        // 上限に達したら取得しない
+        checks.checks = MAX_COMMENTED_CHECKS_PER_CYCLE;
+        assert!(!checks.should_fetch(&mut issue(3, None)));
+
+        // レート残量が少ないときは取得しない（前回の件数を引き継ぐ）
+        let mut checks = CommentCountChecks::load(&db, 1, &existing_updated_map, Some(1)).await;
+        let mut updated = issue(1, Some("2026-10-02T00:00:00Z"));
+        assert!(!checks.should_fetch(&mut updated));
+        assert_eq!(updated.comment_count, Some(7));
+    }
+
+    #[test]
+    fn issues_missing_from_synced_projects_are_reported_as_closed() {
+        let with_key = |id: i64, key: &str| {
+            let mut i = issue(id, None);
+            i.issue_key = key.to_string();
+            i.status = Some(crate::backlog::Status {
+                id: 2,
+                name: "処理中".to_string(),
+            });
+            i
+        };
+        let existing = [
+            with_key(1, "PROJ-1"),
+            with_key(2, "PROJ-2"),
+            with_key(3, "FAILED-3"),
+            with_key(4, "BIG-4"),
+        ];
+        let fetched = std::collections::HashSet::from([1]);
+        let keys = |keys: &[&str]| keys.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+
+        // 取得に失敗したプロジェクト・取得件数が上限に達したプロジェクトの課題は除く
+        let changes = closed_status_changes(
+            1,
+            &existing,
+            &fetched,
+            &keys(&["PROJ", "BIG"]),
+            &keys(&["BIG"]),
+        );
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].issue_key, "PROJ-2");
+        assert_eq!(changes[0].old_status.as_deref(), Some("処理中"));
+        assert_eq!(changes[0].new_status, None);
+        assert!(changes[0].closed);
+        // 別のワークスペースの課題は対象外
+        assert!(closed_status_changes(2, &existing, &fetched, &keys(&["PROJ"]), &[]).is_empty());
+    }
 }
@@ -1,5 +1,346 @@
-use crate::backlog::{Issue, User};
-use chrono::{DateTime, Local, NaiveDate, Utc};
+use crate::backlog::{parse_backlog_date, parse_backlog_datetime, Issue, Notification, User};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, Utc};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+/// 親子課題の扱いを保持する設定キー（`settings` テーブル）。
+///
+/// 値は `off`（既定）/ `parent_bonus` / `children_only`。[`ParentIssueMode`] を参照。
+pub const SETTING_PARENT_ISSUE_MODE: &str = "parent_issue_mode";
+
+/// 子課題を持つ親課題への加点（[`ParentIssueMode::ParentBonus`] のとき）
+pub const PARENT_ISSUE_BONUS: i32 = 10;
+
+/// 課題の種別名ごとの加点を保持する設定キー（`settings` テーブル）。
+///
+/// 値は種別名から加点へのJSONオブジェクト（例: `{"バグ": 25}`）。未設定・不正値は加点なし。
+pub const SETTING_ISSUE_TYPE_BONUS: &str = "issue_type_bonus";
+
+/// 添付ファイルのある課題への加点を保持する設定キー（`settings` テーブル）。
+///
+/// 値は加点（整数）。未設定・不正値は加点なし、範囲外の値は 0〜[`MAX_ATTACHMENT_BONUS`] にクランプする。
+pub const SETTING_ATTACHMENT_BONUS: &str = "attachment_bonus";
+
+/// 添付ファイルのある課題への加点の上限（確認を促す程度の軽い加点にとどめる）
+pub const MAX_ATTACHMENT_BONUS: i32 = 30;
+
+/// チームメンバーが担当する課題への加点（俯瞰用に、自分が担当の場合より弱くする）
+pub const TEAM_ASSIGNEE_BONUS: i32 = 10;
+
+/// 日付の比較に使うタイムゾーンを保持する設定キー（`settings` テーブル）。
+///
+/// 値は UTC からのオフセット（例: `+09:00`・`-05:00`・`UTC`）。未設定・不正値は OS のローカル
+/// タイムゾーン。期限切れ・期限間近など「日付」の判定はこのタイムゾーンの暦日で行い、最終更新からの
+/// 経過日数など「経過時間」の判定はタイムゾーンによらない絶対時刻（UTC）で行う。
+pub const SETTING_TIMEZONE: &str = "timezone";
+
+/// [`SETTING_TIMEZONE`] の値を UTC からのオフセットに変換する
+///
+/// # 引数
+/// * `value` - `+HH:MM` / `-HH:MM` / `UTC` / `Z` 形式の文字列
+///
+/// # 戻り値
+/// オフセット。形式が不正・範囲外（±14時間超）なら `None`
+pub fn parse_timezone(value: &str) -> Option<FixedOffset> {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("utc") || value.eq_ignore_ascii_case("z") {
+        return FixedOffset::east_opt(0);
+    }
+    let (sign, rest) = if let Some(rest) = value.strip_prefix('+') {
+        (1, rest)
+    } else if let Some(rest) = value.strip_prefix('-') {
+        (-1, rest)
+    } else {
+        return None;
+    };
+    let (hours, minutes) = rest.split_once(':')?;
+    let is_two_digits = |part: &str| part.len() == 2 && part.bytes().all(|b| b.is_ascii_digit());
+    if !is_two_digits(hours) || !is_two_digits(minutes) {
+        return None;
+    }
+    let (hours, minutes): (i32, i32) = (hours.parse().ok()?, minutes.parse().ok()?);
+    if hours > 14 || minutes >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// マイルストーンのリリース期限が近いとみなす日数
+const MILESTONE_DUE_SOON_DAYS: i64 = 7;
+
+/// リリース期限を過ぎたマイルストーンを加点の対象にする日数
+///
+/// これより前に期限を過ぎたマイルストーンは、リリース済みのまま閉じ忘れたものとみなす。
+const MILESTONE_OVERDUE_MAX_DAYS: i64 = 14;
+
+/// リリース期限が近いマイルストーンに紐づく課題への加点
+const MILESTONE_DUE_SOON_BONUS: i32 = 30;
+
+/// Backlog 標準の「処理中」ステータスID
+const STATUS_ID_IN_PROGRESS: i64 = 2;
+
+/// 「処理中」のまま更新がないと滞留とみなす日数
+pub const STALE_IN_PROGRESS_DAYS: i64 = 7;
+
+/// 「処理中」で滞留している課題への加点
+pub const STALE_IN_PROGRESS_BONUS: i32 = 20;
+
+/// お知らせ（通知API）で自分宛のメンション・担当があった課題への加点
+pub const NOTIFICATION_MENTION_BONUS: i32 = 40;
+
+/// 説明文・コメントで自分が1回メンションされた課題への加点
+pub const MENTION_BONUS: i32 = 30;
+
+/// 2回目以降にメンションされるたびの追加の加点
+const MENTION_REPEAT_BONUS: i32 = 10;
+
+/// メンションの回数による加点の上限
+pub const MENTION_BONUS_MAX: i32 = 60;
+
+/// メンションのユーザー名に続けて書かれても同じ人とみなす敬称（`@山田さん`）
+const MENTION_HONORIFICS: &[&str] = &["さん", "様", "さま", "殿", "くん", "君", "ちゃん"];
+
+/// コメント数を取得する（コメント数で加点する）対象とするスコアの下限
+///
+/// コメント数の取得は課題ごとに API を1回消費するため、この点数以上の課題に限定する。
+pub const COMMENT_COUNT_MIN_SCORE: i32 = 50;
+
+/// コメント1件あたりの加点
+const COMMENT_BONUS_PER_COMMENT: i32 = 2;
+
+/// コメント数による加点の上限
+pub const COMMENT_BONUS_MAX: i32 = 20;
+
+/// 自分がウォッチしている課題への加点
+pub const WATCHING_BONUS: i32 = 15;
+
+/// 直近のコメントに自分の投稿がある課題（関与している課題）への加点
+pub const COMMENTED_BONUS: i32 = 20;
+
+/// 関与の判定で確認する直近のコメント数
+pub const COMMENTED_CHECK_COUNT: usize = 20;
+
+/// 親子課題の扱い
+///
+/// 親課題のスコアに子課題の存在を反映するか、一覧を子課題に絞るかを選択する。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParentIssueMode {
+    /// 親子関係をスコア・表示に反映しない（既定）
+    Off,
+    /// 子課題を持つ親課題に [`PARENT_ISSUE_BONUS`] を加点する
+    ParentBonus,
+    /// 課題一覧を子課題（親課題IDを持つ課題）のみに絞る
+    ChildrenOnly,
+}
+
+impl ParentIssueMode {
+    /// 設定値から親子課題の扱いを決定する
+    ///
+    /// # 引数
+    /// * `value` - 設定値（`parent_bonus` / `children_only`。それ以外・未設定は `Off`）
+    ///
+    /// # 戻り値
+    /// 親子課題の扱い
+    pub fn from_setting(value: Option<&str>) -> Self {
+        match value.map(str::trim) {
+            Some("parent_bonus") => Self::ParentBonus,
+            Some("children_only") => Self::ChildrenOnly,
+            _ => Self::Off,
+        }
+    }
+}
+
+/// スコアリングの設定
+///
+/// 既定（[`Default`]）はすべての追加加点なし。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScoringConfig {
+    /// 課題の種別名（`issueType.name`）ごとの加点
+    pub issue_type_bonus: HashMap<String, i32>,
+    /// 添付ファイルのある課題への加点
+    pub attachment_bonus: i32,
+    /// チームメンバーのユーザーID（担当する課題に [`TEAM_ASSIGNEE_BONUS`] を加点する）
+    ///
+    /// ユーザーIDは Backlog スペースごとの値なので、ワークスペースごとの設定
+    /// （[`WorkspaceScoringConfig::team_user_ids`]）からだけ設定する。
+    pub team_user_ids: Vec<i64>,
+    /// 日付の比較に使うタイムゾーン（`None` で OS のローカルタイムゾーン。[`SETTING_TIMEZONE`]）
+    pub timezone: Option<FixedOffset>,
+}
+
+/// ワークスペースごとのスコアリング設定（`workspaces.scoring_config` に JSON で保存）
+///
+/// 指定した項目だけグローバルの設定（`settings` テーブル）を上書きする。
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceScoringConfig {
+    /// 課題の種別名ごとの加点
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_type_bonus: Option<HashMap<String, i32>>,
+    /// 添付ファイルのある課題への加点（0〜[`MAX_ATTACHMENT_BONUS`] にクランプする）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_bonus: Option<i32>,
+    /// チームメンバーのユーザーID（グローバルの設定は無く、未指定ならチームなし）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_user_ids: Option<Vec<i64>>,
+}
+
+impl ScoringConfig {
+    /// 設定値からスコアリングの設定を決定する
+    ///
+    /// # 引数
+    /// * `issue_type_bonus` - [`SETTING_ISSUE_TYPE_BONUS`] の値（不正なJSON・未設定は加点なし）
+    ///
+    /// # 戻り値
+    /// スコアリングの設定
+    pub fn from_setting(issue_type_bonus: Option<&str>) -> Self {
+        let issue_type_bonus = issue_type_bonus
+            .and_then(|value| serde_json::from_str(value).ok())
+            .unwrap_or_default();
+        Self {
+            issue_type_bonus,
+            attachment_bonus: 0,
+            team_user_ids: Vec::new(),
+            timezone: None,
+        }
+    }
+
+    /// 添付ファイルのある課題への加点を設定する
+    ///
+    /// # 引数
+    /// * `value` - [`SETTING_ATTACHMENT_BONUS`] の値（不正値・未設定は加点なし）
+    ///
+    /// # 戻り値
+    /// 加点を設定したスコアリングの設定
+    pub fn with_attachment_bonus(mut self, value: Option<&str>) -> Self {
+        self.attachment_bonus = value
+            .and_then(|value| value.trim().parse::<i64>().ok())
+            .map_or(0, |bonus| bonus.clamp(0, MAX_ATTACHMENT_BONUS as i64) as i32);
+        self
+    }
+
+    /// 日付の比較に使うタイムゾーンを設定する
+    ///
+    /// # 引数
+    /// * `value` - [`SETTING_TIMEZONE`] の値（不正値・未設定は OS のローカルタイムゾーン）
+    ///
+    /// # 戻り値
+    /// タイムゾーンを設定したスコアリングの設定
+    pub fn with_timezone(mut self, value: Option<&str>) -> Self {
+        self.timezone = value.and_then(parse_timezone);
+        self
+    }
+
+    /// 基準時刻の、設定したタイムゾーンでの日付（「今日」）を返す
+    ///
+    /// # 引数
+    /// * `now` - 基準時刻
+    ///
+    /// # 戻り値
+    /// 設定したタイムゾーン（未設定なら OS のローカルタイムゾーン）での日付
+    pub fn today(&self, now: DateTime<Utc>) -> NaiveDate {
+        match self.timezone {
+            Some(offset) => now.with_timezone(&offset).date_naive(),
+            None => now.with_timezone(&Local).date_naive(),
+        }
+    }
+
+    /// ワークスペースごとの設定（[`WorkspaceScoringConfig`]）で上書きする
+    ///
+    /// 指定された項目だけを置き換え、省略された項目はこの設定（グローバル）のまま使う。
+    ///
+    /// # 引数
+    /// * `value` - `workspaces.scoring_config` の値（不正なJSON・未設定は上書きしない）
+    ///
+    /// # 戻り値
+    /// ワークスペースの設定を反映したスコアリングの設定
+    pub fn with_workspace_override(mut self, value: Option<&str>) -> Self {
+        let Some(config) =
+            value.and_then(|value| serde_json::from_str::<WorkspaceScoringConfig>(value).ok())
+        else {
+            return self;
+        };
+        if let Some(issue_type_bonus) = config.issue_type_bonus {
+            self.issue_type_bonus = issue_type_bonus;
+        }
+        if let Some(attachment_bonus) = config.attachment_bonus {
+            self.attachment_bonus = attachment_bonus.clamp(0, MAX_ATTACHMENT_BONUS);
+        }
+        if let Some(team_user_ids) = config.team_user_ids {
+            self.team_user_ids = team_user_ids;
+        }
+        self
+    }
+
+    /// 課題の種別に応じた加点を返す
+    ///
+    /// # 引数
+    /// * `issue` - 判定する課題
+    ///
+    /// # 戻り値
+    /// 種別名が加点マップにあればその点数、種別なし・マップにない場合は 0
+    pub fn issue_type_bonus_for(&self, issue: &Issue) -> i32 {
+        issue
+            .issue_type
+            .as_ref()
+            .and_then(|t| self.issue_type_bonus.get(&t.name))
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+/// 関連度スコアの内訳（加点要素ごとの点数）
+///
+/// [`ScoringService::calculate_score_breakdown`] が返す。合計は [`ScoreBreakdown::total`]。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct ScoreBreakdown {
+    /// 自分が担当者
+    pub assignee: i32,
+    /// チームメンバーが担当者（[`ScoringConfig::team_user_ids`]）
+    pub team_assignee: i32,
+    /// 期限切れ・期限間近（担当者が自分の場合のみ）
+    pub due_date: i32,
+    /// 最近の更新（担当者が自分の場合のみ）
+    pub recently_updated: i32,
+    /// メンション（お知らせ、または説明文・コメントでの言及の回数）
+    pub mention: i32,
+    /// マイルストーンのリリース期限間近
+    pub milestone: i32,
+    /// 「処理中」での滞留
+    pub stale_in_progress: i32,
+    /// 課題の種別（[`ScoringConfig::issue_type_bonus`]）
+    pub issue_type: i32,
+    /// 子課題を持つ親課題への加点（[`ParentIssueMode::ParentBonus`] のとき）
+    pub parent: i32,
+    /// コメント数（[`Issue::comment_count`] を取得済みの場合のみ）
+    pub comments: i32,
+    /// 自分がウォッチしている課題（[`Issue::watching`]）
+    pub watching: i32,
+    /// 直近のコメントに自分の投稿がある課題（[`Issue::commented_by_me`]）
+    pub commented: i32,
+    /// 添付ファイルのある課題（[`ScoringConfig::attachment_bonus`]）
+    pub attachments: i32,
+}
+
+impl ScoreBreakdown {
+    /// 内訳の合計（関連度スコア）
+    pub fn total(&self) -> i32 {
+        self.assignee
+            + self.team_assignee
+            + self.due_date
+            + self.recently_updated
+            + self.mention
+            + self.milestone
+            + self.stale_in_progress
+            + self.issue_type
+            + self.parent
+            + self.comments
+            + self.watching
+            + self.commented
+            + self.attachments
+    }
+}
 
 /// スコアリングサービス
 ///
@@ -10,71 +351,903 @@ pub struct ScoringService;
 impl ScoringService {
     /// 課題の関連度スコアを計算
     ///
-    /// 以下の基準でスコアを加算する：
+    /// [`calculate_score_breakdown`](Self::calculate_score_breakdown) の内訳の合計を返す。
+    ///
+    /// # 引数
+    /// * `issue` - スコアを計算する課題
+    /// * `me` - 現在のユーザー情報
+    /// * `config` - スコアリングの設定
+    ///
+    /// # 戻り値
+    /// 計算された関連度スコア（0以上の整数）
+    pub fn calculate_score(issue: &Issue, me: &User, config: &ScoringConfig) -> i32 {
+        Self::calculate_score_breakdown(issue, me, config).total()
+    }
+
+    /// お知らせを考慮して課題の関連度スコアを計算
+    ///
+    /// [`calculate_score_breakdown_with_notifications`](Self::calculate_score_breakdown_with_notifications)
+    /// の内訳の合計を返す。
+    ///
+    /// # 引数
+    /// * `issue` - スコアを計算する課題
+    /// * `me` - 現在のユーザー情報
+    /// * `notified_issue_ids` - 自分宛のお知らせがあった課題ID（取得できなければ `None`）
+    /// * `config` - スコアリングの設定
+    ///
+    /// # 戻り値
+    /// 計算された関連度スコア（0以上の整数）
+    pub fn calculate_score_with_notifications(
+        issue: &Issue,
+        me: &User,
+        notified_issue_ids: Option<&HashSet<i64>>,
+        config: &ScoringConfig,
+    ) -> i32 {
+        Self::calculate_score_breakdown_with_notifications(issue, me, notified_issue_ids, config)
+            .total()
+    }
+
+    /// 自分宛のお知らせ（担当・メンション・通知先指定）があった課題IDを集める
+    ///
+    /// # 引数
+    /// * `notifications` - `GET /notifications` で取得したお知らせ
+    ///
+    /// # 戻り値
+    /// 課題IDの集合
+    pub fn notified_issue_ids(notifications: &[Notification]) -> HashSet<i64> {
+        notifications
+            .iter()
+            .filter(|n| n.is_issue_mention())
+            .filter_map(|n| n.issue.as_ref().map(|i| i.id))
+            .collect()
+    }
+
+    /// 課題の関連度スコアを加点要素ごとの内訳で計算
+    ///
+    /// 以下の基準で加点する：
     /// - 自分が担当者: +50点
+    /// - チームメンバーが担当者: [`TEAM_ASSIGNEE_BONUS`] 点（[`ScoringConfig::team_user_ids`] を設定した場合のみ）
     /// - 期限切れ: +100点
     /// - 期限まで7日以内: +50点
     /// - 3日以内に更新: +50点
-    /// - 説明文に自分の名前が含まれる: +30点
+    /// - 説明文・直近のコメントに自分宛のメンション（`@名前`）: 1回で+30点、2回目以降は1回につき+10点
+    ///   （上限+60点。[`mention_bonus`](Self::mention_bonus)）
+    /// - マイルストーンのリリース期限まで7日以内（超過含む）: +30点（担当者に関わらず）
+    /// - 「処理中」のまま7日以上更新がない（滞留）: +20点（担当者に関わらず）
+    /// - 課題の種別: [`ScoringConfig::issue_type_bonus`] の点数（既定は加点なし）
+    /// - コメント数: 1件につき+2点、上限+20点（[`Issue::comment_count`] を取得済みの場合のみ）
+    /// - 自分がウォッチしている: [`WATCHING_BONUS`] 点（担当者に関わらず）
+    /// - 直近のコメントに自分の投稿がある: [`COMMENTED_BONUS`] 点（担当者に関わらず。
+    ///   [`Issue::commented_by_me`] を判定できた場合のみ）
+    /// - 添付ファイルがある: [`ScoringConfig::attachment_bonus`] の点数（既定は加点なし）
+    ///
+    /// お知らせを考慮する場合は
+    /// [`calculate_score_breakdown_with_notifications`](Self::calculate_score_breakdown_with_notifications)
+    /// を使う。
+    /// 期限・更新の加点は自分が担当者の場合のみ。親課題への加点は課題単体では決まらないため
+    /// 含めない（必要なら呼び出し側で [`ScoreBreakdown::parent`] に設定する）。
     ///
     /// # 引数
     /// * `issue` - スコアを計算する課題
     /// * `me` - 現在のユーザー情報
+    /// * `config` - スコアリングの設定
     ///
     /// # 戻り値
-    /// 計算された関連度スコア（0以上の整数）
-    pub fn calculate_score(issue: &Issue, me: &User) -> i32 {
-        let mut score = 0;
+    /// 加点要素ごとの内訳
+    pub fn calculate_score_breakdown(
+        issue: &Issue,
+        me: &User,
+        config: &ScoringConfig,
+    ) -> ScoreBreakdown {
+        Self::calculate_score_breakdown_with_notifications(issue, me, None, config)
+    }
+
+    /// お知らせを考慮して関連度スコアの内訳を計算
+    ///
+    /// メンションの判定以外は [`calculate_score_breakdown`](Self::calculate_score_breakdown) と同じ。
+    /// お知らせを取得できた場合（`Some`）は、自分宛のお知らせがあった課題に
+    /// [`NOTIFICATION_MENTION_BONUS`] を加点する（メンションの回数による加点の方が大きければそちら）。
+    /// 取得できなかった場合（`None`）は説明文・直近のコメントで自分がメンションされた回数で判定する。
+    ///
+    /// # 引数
+    /// * `issue` - スコアを計算する課題
+    /// * `me` - 現在のユーザー情報
+    /// * `notified_issue_ids` - 自分宛のお知らせがあった課題ID（取得できなければ `None`）
+    /// * `config` - スコアリングの設定
+    ///
+    /// # 戻り値
+    /// 加点要素ごとの内訳
+    pub fn calculate_score_breakdown_with_notifications(
+        issue: &Issue,
+        me: &User,
+        notified_issue_ids: Option<&HashSet<i64>>,
+        config: &ScoringConfig,
+    ) -> ScoreBreakdown {
+        let mut breakdown = ScoreBreakdown::default();
+        // 期限は設定したタイムゾーンの暦日で、更新・滞留は絶対時刻で判定する
+        let now = Utc::now();
+        let today = config.today(now);
 
         // 1. 担当者が自分かどうかをチェック
         if let Some(assignee) = &issue.assignee {
             if assignee.id == me.id {
                 // 基本スコア: 自分が担当者
-                score += 50;
+                breakdown.assignee = 50;
 
                 // 期限日のチェック
-                if let Some(due_date_str) = &issue.due_date {
-                    // 日付フォーマットのパース（複数形式に対応）
-                    if let Ok(due_date) =
-                        NaiveDate::parse_from_str(due_date_str, "%Y-%m-%dT%H:%M:%SZ")
-                            .or_else(|_| NaiveDate::parse_from_str(due_date_str, "%Y-%m-%d"))
-                    {
-                        let today = Local::now().date_naive();
-                        let diff = (due_date - today).num_days();
-
-                        if diff < 0 {
-                            // 期限切れ → 最優先
-                            score += 100;
-                        } else if diff <= 7 {
-                            // 期限まで7日以内 → 優先度高
-                            score += 50;
-                        }
-                    }
+                // 日付フォーマットのパース（複数形式に対応）
+                if let Some(due_date) = issue.due_date.as_deref().and_then(parse_backlog_date) {
+                    breakdown.due_date = Self::due_date_bonus(due_date, today);
                 }
 
                 // 最近更新されたかどうかをチェック（3日以内）
-                if let Some(updated_str) = &issue.updated {
-                    if let Ok(updated) = DateTime::parse_from_rfc3339(updated_str) {
-                        let updated_utc = updated.with_timezone(&Utc);
-                        let now_utc = Utc::now();
-                        if (now_utc - updated_utc).num_days() <= 3 {
-                            // 最近更新された → 優先度高
-                            score += 50;
-                        }
+                if let Some(updated) = issue.updated.as_deref().and_then(parse_backlog_datetime) {
+                    if (now - updated).num_days() <= 3 {
+                        // 最近更新された → 優先度高
+                        breakdown.recently_updated = 50;
                     }
                 }
+            } else if config.team_user_ids.contains(&assignee.id) {
+                // チームメンバーが担当者 → 俯瞰用の弱い加点（期限・更新の加点は自分の担当のみ）
+                breakdown.team_assignee = TEAM_ASSIGNEE_BONUS;
             }
         }
 
-        // 2. メンションのチェック（簡易版）
-        // 注: 本来はコメントや通知APIを使用すべきだが、ここでは説明文に名前が含まれるかで判定
-        if let Some(desc) = &issue.description {
-            if desc.contains(&me.name) {
-                // 自分の名前が含まれる → 重要
-                score += 30;
+        // 2. マイルストーンのリリース期限をチェック
+        // 複数のマイルストーンがある場合はいずれかの期限が対象の範囲にあれば加点し、
+        // 期限未設定のものは無視する。期限を大きく過ぎたもの（閉じ忘れ）は対象にしない
+        let release_due_soon = issue
+            .milestone
+            .iter()
+            .flatten()
+            .filter_map(|m| m.release_due_date.as_deref().and_then(parse_backlog_date))
+            .map(|release_date| (release_date - today).num_days())
+            .any(|diff| (-MILESTONE_OVERDUE_MAX_DAYS..=MILESTONE_DUE_SOON_DAYS).contains(&diff));
+        if release_due_soon {
+            // リリースが近い（または過ぎたばかりの）マイルストーン → 優先度高
+            breakdown.milestone = MILESTONE_DUE_SOON_BONUS;
+        }
+
+        // 3. 「処理中」ステータスでの滞留をチェック
+        breakdown.stale_in_progress = Self::stale_in_progress_bonus(issue, now);
+
+        // 4. 課題の種別による加点（設定した種別のみ。種別なしは加点しない）
+        breakdown.issue_type = config.issue_type_bonus_for(issue);
+
+        // 5. コメント数による加点（議論が活発な課題。取得済みの場合のみ）
+        breakdown.comments = issue.comment_count.map_or(0, Self::comment_bonus);
+
+        // 6. ウォッチ中の課題への加点（担当者でなくても把握しておきたい課題）
+        if issue.watching {
+            breakdown.watching = WATCHING_BONUS;
+        }
+
+        // 7. 自分がコメントした課題への加点（担当者でなくても関与している課題）
+        if issue.commented_by_me == Some(true) {
+            breakdown.commented = COMMENTED_BONUS;
+        }
+
+        // 8. 添付ファイルのある課題への加点（資料の確認が必要なことが多い。設定した場合のみ）
+        if issue.has_attachments() {
+            breakdown.attachments = config.attachment_bonus;
+        }
+
+        // 9. メンションのチェック
+        // お知らせ（通知API）を取得できていればそれで判定し、取得できなければ
+        // 説明文・コメントで `@名前` とメンションされた回数で判定する（簡易版へのフォールバック）。
+        // 説明文は記法記号（リンク・装飾など）を除いたプレーンテキストで照合する
+        let description_mentions = issue.description.as_deref().map_or(0, |desc| {
+            Self::count_mentions(&crate::markup::to_plain_text(desc), &me.name)
+        });
+        let comment_mentions = usize::try_from(issue.comment_mentions.unwrap_or(0)).unwrap_or(0);
+        let mention_bonus = Self::mention_bonus(description_mentions + comment_mentions);
+        match notified_issue_ids {
+            Some(ids) => {
+                if ids.contains(&issue.id) {
+                    // 自分宛のメンション・担当のお知らせあり → 重要（何度も呼ばれていればさらに加点）
+                    breakdown.mention = NOTIFICATION_MENTION_BONUS.max(mention_bonus);
+                }
+            }
+            None => {
+                // 自分がメンションされている → 重要（回数が多いほど緊急度が高い）
+                breakdown.mention = mention_bonus;
             }
         }
 
-        score
+        breakdown
+    }
+
+    /// 自分が担当の課題の期限日に応じた加点を返す
+    ///
+    /// # 引数
+    /// * `due_date` - 期限日
+    /// * `today` - 判定の基準日（[`ScoringConfig::today`]）
+    ///
+    /// # 戻り値
+    /// 期限切れなら 100、期限まで7日以内なら 50、それ以外は 0
+    fn due_date_bonus(due_date: NaiveDate, today: NaiveDate) -> i32 {
+        let diff = (due_date - today).num_days();
+        if diff < 0 {
+            // 期限切れ → 最優先
+            100
+        } else if diff <= 7 {
+            // 期限まで7日以内 → 優先度高
+            50
+        } else {
+            0
+        }
+    }
+
+    /// 「処理中」のまま動いていない課題への加点を計算
+    ///
+    /// 現在のステータスになってからの経過は取得できないため、最終更新日時（`updated`）からの
+    /// 経過日数で推定する。ステータスが「処理中」（ID またはステータス名で判定）で、
+    /// [`STALE_IN_PROGRESS_DAYS`] 日以上更新がなければ加点する。ステータス・更新日時が無い場合は加点しない。
+    ///
+    /// # 引数
+    /// * `issue` - 判定する課題
+    /// * `now` - 判定の基準時刻
+    ///
+    /// # 戻り値
+    /// 滞留していれば [`STALE_IN_PROGRESS_BONUS`]、それ以外は 0
+    pub fn stale_in_progress_bonus(issue: &Issue, now: DateTime<Utc>) -> i32 {
+        let in_progress = issue.status.as_ref().is_some_and(|status| {
+            status.id == STATUS_ID_IN_PROGRESS
+                || status.name == "処理中"
+                || status.name == "In Progress"
+        });
+        if !in_progress {
+            return 0;
+        }
+        let Some(updated) = issue.updated.as_deref().and_then(parse_backlog_datetime) else {
+            return 0;
+        };
+        if (now - updated).num_days() >= STALE_IN_PROGRESS_DAYS {
+            STALE_IN_PROGRESS_BONUS
+        } else {
+            0
+        }
+    }
+
+    /// 直近のコメントに自分の投稿があるかを判定する
+    ///
+    /// 新しい順のコメントのうち先頭 [`COMMENTED_CHECK_COUNT`] 件だけを確認する。
+    /// 投稿者が取得できないコメントは自分の投稿とみなさない。
+    ///
+    /// # 引数
+    /// * `comments` - 新しい順のコメント
+    /// * `me_id` - 自分のユーザーID
+    ///
+    /// # 戻り値
+    /// 自分の投稿があれば `true`
+    pub fn has_commented(comments: &[crate::db::Comment], me_id: i64) -> bool {
+        comments
+            .iter()
+            .take(COMMENTED_CHECK_COUNT)
+            .any(|c| c.created_user.as_ref().is_some_and(|u| u.id == me_id))
+    }
+
+    /// テキストからBacklogのメンション（`@ユーザー名`）を抽出する
+    ///
+    /// `@` の直後から空白・句読点・括弧などの区切りまでをユーザー名とみなす。
+    /// メールアドレス（`user@example.com`）の `@` はメンションとして扱わない。
+    ///
+    /// # 引数
+    /// * `desc` - 対象のテキスト（説明文・コメント）
+    ///
+    /// # 戻り値
+    /// メンションされたユーザー名（出現順。同じ名前の重複も含む）
+    pub fn extract_mentions(desc: &str) -> Vec<String> {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        let pattern = PATTERN.get_or_init(|| {
+            Regex::new(
+                r"(?:^|[^A-Za-z0-9._%+\-@])@([^\s@、。，．,.!！?？:：;；()（）「」『』\[\]<>]+)",
+            )
+            .expect("mention pattern must be valid")
+        });
+        pattern
+            .captures_iter(desc)
+            .map(|captures| captures[1].to_string())
+            .collect()
+    }
+
+    /// テキスト中で自分がメンションされた回数を数える
+    ///
+    /// [`Self::extract_mentions`] で抽出したユーザー名のうち、自分の名前と完全に一致するものだけを
+    /// 数える（`山田` は `@山田花子` に一致しない）。`@山田さん` のように敬称を続けた表記は一致とみなし、
+    /// 敬称の後に区切りなく本文が続く表記（`@山田さん確認してください`）も敬称を区切りとして数える。
+    /// 名前に空白を含む場合は空白を除いた表記（`@山田太郎`）と照合する。
+    /// 名前が空（空白のみを含む）の場合は 0 を返す。
+    ///
+    /// # 引数
+    /// * `text` - 対象のテキスト
+    /// * `name` - 自分の名前
+    ///
+    /// # 戻り値
+    /// メンションされた回数
+    pub fn count_mentions(text: &str, name: &str) -> usize {
+        let name: String = name.split_whitespace().collect();
+        if name.is_empty() {
+            return 0;
+        }
+        Self::extract_mentions(text)
+            .iter()
+            .filter(|mention| {
+                let Some(rest) = mention.strip_prefix(name.as_str()) else {
+                    return false;
+                };
+                rest.is_empty()
+                    || MENTION_HONORIFICS
+                        .iter()
+                        .any(|honorific| rest.starts_with(honorific))
+            })
+            .count()
+    }
+
+    /// 直近のコメントで自分がメンションされた回数を数える
+    ///
+    /// 新しい順のコメントのうち先頭 [`COMMENTED_CHECK_COUNT`] 件を対象とし、自分の投稿は除く。
+    /// 本文は記法記号を除いたプレーンテキストで照合する。
+    ///
+    /// # 引数
+    /// * `comments` - 新しい順のコメント
+    /// * `me` - 自分のユーザー情報
+    ///
+    /// # 戻り値
+    /// 出現回数の合計
+    pub fn comment_mentions(comments: &[crate::db::Comment], me: &User) -> i64 {
+        comments
+            .iter()
+            .take(COMMENTED_CHECK_COUNT)
+            .filter(|c| !c.created_user.as_ref().is_some_and(|u| u.id == me.id))
+            .filter_map(|c| c.content.as_deref())
+            .map(|content| Self::count_mentions(&crate::markup::to_plain_text(content), &me.name))
+            .sum::<usize>() as i64
+    }
+
+    /// メンションの回数による加点を計算
+    ///
+    /// # 引数
+    /// * `mentions` - メンションの回数
+    ///
+    /// # 戻り値
+    /// 0回は 0、1回は [`MENTION_BONUS`]、2回目以降は1回につき10点を加え、上限 [`MENTION_BONUS_MAX`]
+    pub fn mention_bonus(mentions: usize) -> i32 {
+        if mentions == 0 {
+            return 0;
+        }
+        let repeats = i32::try_from(mentions - 1).unwrap_or(i32::MAX);
+        MENTION_BONUS
+            .saturating_add(repeats.saturating_mul(MENTION_REPEAT_BONUS))
+            .min(MENTION_BONUS_MAX)
+    }
+
+    /// コメント数による加点を計算
+    ///
+    /// # 引数
+    /// * `comment_count` - 課題のコメント数
+    ///
+    /// # 戻り値
+    /// 1件につき2点、[`COMMENT_BONUS_MAX`] を上限とする加点
+    pub fn comment_bonus(comment_count: i64) -> i32 {
+        let bonus = comment_count.max(0).saturating_mul(COMMENT_BONUS_PER_COMMENT as i64);
+        bonus.min(COMMENT_BONUS_MAX as i64) as i32
+    }
+
+    /// 子課題を持つ親課題のIDを集める
+    ///
+    /// 同じ同期バッチ内の課題の `parent_issue_id` を集約する（取得対象外の子課題は考慮しない）。
+    ///
+    /// # 引数
+    /// * `issues` - 同期した課題のスライス
+    ///
+    /// # 戻り値
+    /// 子課題を持つ親課題IDの集合
+    pub fn parent_issue_ids(issues: &[Issue]) -> HashSet<i64> {
+        issues.iter().filter_map(|i| i.parent_issue_id).collect()
+    }
+
+    /// 親課題への加点を計算
+    ///
+    /// # 引数
+    /// * `issue` - 加点を判定する課題
+    /// * `parent_ids` - [`Self::parent_issue_ids`] で集めた親課題IDの集合
+    ///
+    /// # 戻り値
+    /// 子課題を持つ親課題なら [`PARENT_ISSUE_BONUS`]、それ以外は 0
+    pub fn parent_bonus(issue: &Issue, parent_ids: &HashSet<i64>) -> i32 {
+        if parent_ids.contains(&issue.id) {
+            PARENT_ISSUE_BONUS
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(id: i64, parent_issue_id: Option<i64>) -> Issue {
+        let json = format!(r#"{{ "id": {id}, "issueKey": "PROJ-{id}", "summary": "" }}"#);
+        let mut issue: Issue = serde_json::from_str(&json).unwrap();
+        issue.parent_issue_id = parent_issue_id;
+        issue
+    }
+
+    /// スコア計算の基準となる自分（ユーザーID 1）
+    fn me() -> User {
+        User {
+            id: 1,
+            name: "me".to_string(),
+            mail_address: None,
+            role_type: None,
+        }
+    }
+
+    #[test]
+    fn parent_bonus_applies_only_to_issues_with_children() {
+        let issues = vec![issue(1, None), issue(2, Some(1)), issue(3, None)];
+        let parent_ids = ScoringService::parent_issue_ids(&issues);
+        assert_eq!(ScoringService::parent_bonus(&issues[0], &parent_ids), PARENT_ISSUE_BONUS);
+        assert_eq!(ScoringService::parent_bonus(&issues[1], &parent_ids), 0);
+        assert_eq!(ScoringService::parent_bonus(&issues[2], &parent_ids), 0);
+    }
+
+    #[test]
+    fn milestone_due_soon_adds_bonus_using_nearest_release() {
+        let me = me();
+        let config = ScoringConfig::default();
+        let date = |days: i64| {
+            (Local::now().date_naive() + chrono::Duration::days(days))
+                .format("%Y-%m-%d")
+                .to_string()
+        };
+        let milestone = |release: Option<String>| crate::backlog::Milestone {
+            id: 1,
+            name: "v1.0".to_string(),
+            release_due_date: release,
+        };
+
+        // 複数ある場合は、期限の近いもの（3日後）があれば加点する。
+        let mut target = issue(1, None);
+        target.milestone = Some(vec![
+            milestone(Some(date(30))),
+            milestone(Some(date(3))),
+            milestone(None),
+        ]);
+        assert_eq!(ScoringService::calculate_score(&target, &me, &config), MILESTONE_DUE_SOON_BONUS);
+
+        // 期限が遠い・未設定・マイルストーン無しは加点しない。
+        target.milestone = Some(vec![milestone(Some(date(30))), milestone(None)]);
+        assert_eq!(ScoringService::calculate_score(&target, &me, &config), 0);
+
+        // 期限を過ぎたばかりなら加点し、大きく過ぎたもの（閉じ忘れ）は加点しない。
+        let score = |target: &Issue| ScoringService::calculate_score(target, &me, &config);
+        target.milestone = Some(vec![milestone(Some(date(-MILESTONE_OVERDUE_MAX_DAYS)))]);
+        assert_eq!(score(&target), MILESTONE_DUE_SOON_BONUS);
+        target.milestone = Some(vec![milestone(Some(date(-MILESTONE_OVERDUE_MAX_DAYS - 1)))]);
+        assert_eq!(score(&target), 0);
+        // 閉じ忘れの古いマイルストーンがあっても、期限の近いものがあれば加点する。
+        target.milestone = Some(vec![milestone(Some(date(-365))), milestone(Some(date(3)))]);
+        assert_eq!(score(&target), MILESTONE_DUE_SOON_BONUS);
+        target.milestone = None;
+        assert_eq!(ScoringService::calculate_score(&target, &me, &config), 0);
+    }
+
+    #[test]
+    fn stale_in_progress_bonus_boundaries() {
+        let now = DateTime::parse_from_rfc3339("2026-06-15T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let status = |id: i64, name: &str| crate::backlog::Status {
+            id,
+            name: name.to_string(),
+        };
+        let mut target = issue(1, None);
+        target.status = Some(status(2, "処理中"));
+
+        // ちょうど7日前 → 加点
+        target.updated = Some("2026-06-08T12:00:00Z".to_string());
+        assert_eq!(ScoringService::stale_in_progress_bonus(&target, now), STALE_IN_PROGRESS_BONUS);
+        // 7日未満（6日23時間59分前）→ 加点なし
+        target.updated = Some("2026-06-08T12:01:00Z".to_string());
+        assert_eq!(ScoringService::stale_in_progress_bonus(&target, now), 0);
+
+        // 更新日時が無い → スキップ
+        target.updated = None;
+        assert_eq!(ScoringService::stale_in_progress_bonus(&target, now), 0);
+
+        // 完了・ステータス無しは加点しない
+        target.updated = Some("2026-05-01T00:00:00Z".to_string());
+        target.status = Some(status(4, "完了"));
+        assert_eq!(ScoringService::stale_in_progress_bonus(&target, now), 0);
+        target.status = None;
+        assert_eq!(ScoringService::stale_in_progress_bonus(&target, now), 0);
+
+        // ステータス名でも判定する（カスタム ID の「処理中」）
+        target.status = Some(status(100, "処理中"));
+        assert_eq!(ScoringService::stale_in_progress_bonus(&target, now), STALE_IN_PROGRESS_BONUS);
+    }
+
+    #[test]
+    fn parent_issue_mode_from_setting() {
+        assert_eq!(ParentIssueMode::from_setting(None), ParentIssueMode::Off);
+        assert_eq!(
+            ParentIssueMode::from_setting(Some("parent_bonus")),
+            ParentIssueMode::ParentBonus
+        );
+        assert_eq!(
+            ParentIssueMode::from_setting(Some("children_only")),
+            ParentIssueMode::ChildrenOnly
+        );
+        assert_eq!(ParentIssueMode::from_setting(Some("other")), ParentIssueMode::Off);
+    }
+
+    #[test]
+    fn breakdown_total_matches_calculate_score() {
+        let me = me();
+        let today = Local::now().date_naive();
+        let issue: Issue = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "issueKey": "PROJ-1",
+            "summary": "s",
+            "description": "@me 確認お願いします",
+            "assignee": { "id": 1, "name": "me" },
+            "dueDate": (today - chrono::Duration::days(1)).format("%Y-%m-%d").to_string(),
+            "updated": Utc::now().to_rfc3339(),
+            "milestone": [{
+                "id": 1,
+                "name": "v1",
+                "releaseDueDate": today.format("%Y-%m-%d").to_string(),
+            }],
+        }))
+        .unwrap();
+
+        let config = ScoringConfig::default();
+        let breakdown = ScoringService::calculate_score_breakdown(&issue, &me, &config);
+        assert_eq!(breakdown.assignee, 50);
+        assert_eq!(breakdown.due_date, 100);
+        assert_eq!(breakdown.recently_updated, 50);
+        assert_eq!(breakdown.mention, 30);
+        assert_eq!(breakdown.milestone, MILESTONE_DUE_SOON_BONUS);
+        assert_eq!(breakdown.stale_in_progress, 0);
+        assert_eq!(breakdown.total(), ScoringService::calculate_score(&issue, &me, &config));
+        assert_eq!(breakdown.total(), 260);
+    }
+
+    #[test]
+    fn notification_mentions_replace_description_match() {
+        let me = me();
+        let mut target = issue(1, None);
+        target.description = Some("@me に確認".to_string());
+        let other = issue(2, None);
+
+        // お知らせを取得できない場合は説明文マッチにフォールバック
+        let config = ScoringConfig::default();
+        let score = |issue: &Issue, ids: Option<&HashSet<i64>>| {
+            ScoringService::calculate_score_with_notifications(issue, &me, ids, &config)
+        };
+        assert_eq!(score(&target, None), 30);
+
+        // お知らせを取得できた場合はお知らせのあった課題だけに加点する
+        let notifications: Vec<Notification> = serde_json::from_str(
+            r#"[{"id": 1, "reason": 2, "issue": {"id": 2}},
+                {"id": 2, "reason": 6, "issue": {"id": 1}}]"#,
+        )
+        .unwrap();
+        let ids = ScoringService::notified_issue_ids(&notifications);
+        assert_eq!(ids, HashSet::from([2]));
+        assert_eq!(score(&target, Some(&ids)), 0);
+        assert_eq!(score(&other, Some(&ids)), NOTIFICATION_MENTION_BONUS);
+    }
+
+    #[test]
+    fn mention_bonus_grows_with_mention_count() {
+        let me = User {
+            name: "山田".to_string(),
+            ..me()
+        };
+        let config = ScoringConfig::default();
+        let score = |description: &str, comment_mentions: Option<i64>| {
+            let mut target = issue(1, None);
+            target.description = Some(description.to_string());
+            target.comment_mentions = comment_mentions;
+            ScoringService::calculate_score_breakdown(&target, &me, &config).mention
+        };
+
+        assert_eq!(score("確認してください", None), 0);
+        // 敬称の後に区切りなく本文が続いても、敬称を区切りとしてメンションとみなす
+        assert_eq!(score("@山田さん確認してください", None), MENTION_BONUS);
+        assert_eq!(score("@山田さん 確認してください", None), MENTION_BONUS);
+        assert_eq!(score("@山田さん、@山田 の確認を", None), 40);
+        // コメントでの回数も合算する
+        assert_eq!(score("@山田 確認してください", Some(2)), 50);
+        assert_eq!(score("", Some(1)), MENTION_BONUS);
+        // 上限で頭打ちになる
+        assert_eq!(score("@山田 @山田 @山田 @山田 @山田 @山田", None), MENTION_BONUS_MAX);
+        // 名前の一部が一致するだけのメンションや、メンション記法でない名前は数えない
+        assert_eq!(score("@山田花子 確認してください。山田の件です", None), 0);
+
+        // お知らせがあった課題は、回数による加点の方が大きければそちらを採用する
+        let mut target = issue(1, None);
+        target.description = Some("@山田 @山田 @山田".to_string());
+        let mention = |ids: Option<&HashSet<i64>>| {
+            ScoringService::calculate_score_breakdown_with_notifications(&target, &me, ids, &config)
+                .mention
+        };
+        assert_eq!(mention(Some(&HashSet::from([1]))), 50);
+        assert_eq!(mention(Some(&HashSet::from([2]))), 0);
+    }
+
+    #[test]
+    fn extract_mentions_matches_whole_user_names_only() {
+        assert_eq!(
+            ScoringService::extract_mentions("@山田花子 さん、@佐藤\u{3000}確認を（@tanaka）"),
+            vec!["山田花子", "佐藤", "tanaka"]
+        );
+        // メールアドレスはメンションではない
+        assert!(ScoringService::extract_mentions("yamada@example.com へ送付").is_empty());
+
+        // 名前の一部だけが一致するメンションは数えない
+        let text = "@山田花子 確認をお願いします";
+        assert_eq!(ScoringService::count_mentions(text, "山田"), 0);
+        assert_eq!(ScoringService::count_mentions(text, "山田花子"), 1);
+        assert_eq!(ScoringService::count_mentions("@山田様、@山田くん", "山田"), 2);
+        assert_eq!(ScoringService::count_mentions("@山田様宛です", "山田"), 1);
+        assert_eq!(ScoringService::count_mentions("@山田確認してください", "山田"), 0);
+        // 空白を含む名前は空白を除いた表記と照合する
+        assert_eq!(ScoringService::count_mentions("@山田太郎 確認を", "山田 太郎"), 1);
+    }
+
+    #[test]
+    fn count_mentions_ignores_empty_name() {
+        assert_eq!(ScoringService::count_mentions("@me and @me", "me"), 2);
+        assert_eq!(ScoringService::count_mentions("何でも一致しない", ""), 0);
+        assert_eq!(ScoringService::count_mentions("何でも一致しない", "  "), 0);
+        assert_eq!(ScoringService::mention_bonus(0), 0);
+        assert_eq!(ScoringService::mention_bonus(1), MENTION_BONUS);
+        assert_eq!(ScoringService::mention_bonus(usize::MAX), MENTION_BONUS_MAX);
+
+        let comments: Vec<crate::db::Comment> = serde_json::from_str(
+            r#"[
+                {"id": 3, "content": "@me さん、@me さんへの依頼です",
+                 "createdUser": {"id": 2, "name": "other"}},
+                {"id": 2, "content": "@me の返信", "createdUser": {"id": 1, "name": "me"}},
+                {"id": 1, "content": "@me 確認"}
+            ]"#,
+        )
+        .unwrap();
+        let me = me();
+        // 自分の投稿は数えない
+        assert_eq!(ScoringService::comment_mentions(&comments, &me), 3);
+    }
+
+    #[test]
+    fn issue_type_bonus_follows_config() {
+        let me = me();
+        let typed = |name: Option<&str>| {
+            let mut issue = issue(1, None);
+            issue.issue_type = name.map(|n| crate::backlog::IssueType {
+                id: 1,
+                name: n.to_string(),
+            });
+            issue
+        };
+
+        // 既定は加点なし
+        let default = ScoringConfig::default();
+        assert_eq!(ScoringService::calculate_score(&typed(Some("バグ")), &me, &default), 0);
+
+        let config = ScoringConfig::from_setting(Some(r#"{"バグ": 25, "タスク": 5}"#));
+        let bug = typed(Some("バグ"));
+        let breakdown = ScoringService::calculate_score_breakdown(&bug, &me, &config);
+        assert_eq!(breakdown.issue_type, 25);
+        assert_eq!(breakdown.total(), 25);
+        assert_eq!(ScoringService::calculate_score(&typed(Some("要望")), &me, &config), 0);
+        assert_eq!(ScoringService::calculate_score(&typed(None), &me, &config), 0);
+
+        // 不正な設定値は加点なし
+        assert_eq!(ScoringConfig::from_setting(Some("not json")), ScoringConfig::default());
+    }
+
+    #[test]
+    fn attachment_bonus_applies_only_when_configured() {
+        let me = me();
+        let mut attached = issue(1, None);
+        attached.attachments = Some(vec![crate::backlog::Attachment {
+            id: 10,
+            name: "仕様書.pdf".to_string(),
+            size: 2048,
+        }]);
+
+        // 既定は加点なし
+        let default = ScoringConfig::default();
+        assert_eq!(ScoringService::calculate_score(&attached, &me, &default), 0);
+
+        let config = ScoringConfig::default().with_attachment_bonus(Some("10"));
+        let breakdown = ScoringService::calculate_score_breakdown(&attached, &me, &config);
+        assert_eq!(breakdown.attachments, 10);
+        assert_eq!(breakdown.total(), 10);
+        assert_eq!(ScoringService::calculate_score(&issue(2, None), &me, &config), 0);
+
+        // 範囲外はクランプし、不正値は加点なし
+        let config = ScoringConfig::default().with_attachment_bonus(Some("1000"));
+        assert_eq!(config.attachment_bonus, MAX_ATTACHMENT_BONUS);
+        let config = ScoringConfig::default().with_attachment_bonus(Some("many"));
+        assert_eq!(config.attachment_bonus, 0);
+    }
+
+    #[test]
+    fn team_assignee_adds_weak_bonus_separate_from_own_assignment() {
+        let me = me();
+        let assigned = |assignee_id: i64| {
+            let mut issue = issue(1, None);
+            issue.assignee = Some(User {
+                id: assignee_id,
+                name: format!("user{assignee_id}"),
+                mail_address: None,
+                role_type: None,
+            });
+            issue
+        };
+        let config = ScoringConfig::default()
+            .with_workspace_override(Some(r#"{"team_user_ids": [1, 2, 3]}"#));
+
+        // 自分の担当は従来通り（チームに自分が含まれていても +50 のみ）
+        let own = ScoringService::calculate_score_breakdown(&assigned(1), &me, &config);
+        assert_eq!(own.assignee, 50);
+        assert_eq!(own.team_assignee, 0);
+        // チームメンバーの担当は弱い加点
+        let team = ScoringService::calculate_score_breakdown(&assigned(2), &me, &config);
+        assert_eq!(team.assignee, 0);
+        assert_eq!(team.team_assignee, TEAM_ASSIGNEE_BONUS);
+        assert_eq!(team.total(), TEAM_ASSIGNEE_BONUS);
+        // チーム外の担当は加点なし
+        assert_eq!(ScoringService::calculate_score(&assigned(9), &me, &config), 0);
+
+        // チーム未設定・不正値は従来通り
+        let default = ScoringConfig::default();
+        assert_eq!(ScoringService::calculate_score(&assigned(2), &me, &default), 0);
+        let invalid =
+            ScoringConfig::default().with_workspace_override(Some(r#"{"team_user_ids": "2,3"}"#));
+        assert!(invalid.team_user_ids.is_empty());
+    }
+
+    #[test]
+    fn workspace_override_replaces_only_given_fields() {
+        let global = ScoringConfig {
+            team_user_ids: vec![2],
+            ..ScoringConfig::from_setting(Some(r#"{"バグ": 20}"#)).with_attachment_bonus(Some("10"))
+        };
+
+        let config = global
+            .clone()
+            .with_workspace_override(Some(r#"{"attachment_bonus": 25, "team_user_ids": []}"#));
+        assert_eq!(config.attachment_bonus, 25);
+        assert!(config.team_user_ids.is_empty());
+        // 省略した項目はグローバルの設定のまま
+        assert_eq!(config.issue_type_bonus, global.issue_type_bonus);
+
+        // 範囲外はクランプする
+        let config = global.clone().with_workspace_override(Some(r#"{"attachment_bonus": 99}"#));
+        assert_eq!(config.attachment_bonus, MAX_ATTACHMENT_BONUS);
+        // 未設定・不正なJSON・未知の項目はグローバルの設定のまま
+        for value in [None, Some("not json"), Some(r#"{"unknown": 1}"#)] {
+            assert_eq!(global.clone().with_workspace_override(value), global);
+        }
+    }
+
+    #[test]
+    fn comment_count_adds_capped_bonus() {
+        assert_eq!(ScoringService::comment_bonus(0), 0);
+        assert_eq!(ScoringService::comment_bonus(3), 6);
+        assert_eq!(ScoringService::comment_bonus(100), COMMENT_BONUS_MAX);
+        assert_eq!(ScoringService::comment_bonus(-1), 0);
+
+        let me = me();
+        let config = ScoringConfig::default();
+        let mut target = issue(1, None);
+        // 未取得なら加点なし
+        assert_eq!(ScoringService::calculate_score(&target, &me, &config), 0);
+        target.comment_count = Some(5);
+        let breakdown = ScoringService::calculate_score_breakdown(&target, &me, &config);
+        assert_eq!(breakdown.comments, 10);
+        assert_eq!(breakdown.total(), 10);
+    }
+
+    #[test]
+    fn watching_issue_gets_bonus_regardless_of_assignee() {
+        let me = me();
+        let config = ScoringConfig::default();
+        let mut target = issue(1, None);
+        assert_eq!(ScoringService::calculate_score(&target, &me, &config), 0);
+        target.watching = true;
+        let breakdown = ScoringService::calculate_score_breakdown(&target, &me, &config);
+        assert_eq!(breakdown.watching, WATCHING_BONUS);
+        assert_eq!(breakdown.total(), WATCHING_BONUS);
+    }
+
+    #[test]
+    fn commented_issue_adds_bonus_only_when_checked() {
+        let me = me();
+        let comment = |user_id: Option<i64>| crate::db::Comment {
+            comment_id: 1,
+            content: None,
+            created_at: None,
+            created_user: user_id.map(|id| User {
+                id,
+                name: String::new(),
+                mail_address: None,
+                role_type: None,
+            }),
+        };
+
+        // 確認するのは新しい順の先頭 COMMENTED_CHECK_COUNT 件だけ
+        let mut comments = vec![comment(Some(2)), comment(None), comment(Some(1))];
+        assert!(ScoringService::has_commented(&comments, me.id));
+        comments.splice(0..0, (0..COMMENTED_CHECK_COUNT).map(|_| comment(Some(2))));
+        assert!(!ScoringService::has_commented(&comments, me.id));
+
+        // 判定できなかった（None）・自分の投稿が無い場合は加点しない
+        let config = ScoringConfig::default();
+        let mut target = issue(1, None);
+        let cases = [(None, 0), (Some(false), 0), (Some(true), COMMENTED_BONUS)];
+        for (commented_by_me, expected) in cases {
+            target.commented_by_me = commented_by_me;
+            let breakdown = ScoringService::calculate_score_breakdown(&target, &me, &config);
+            assert_eq!(breakdown.commented, expected);
+            assert_eq!(breakdown.total(), expected);
+        }
+    }
+
+    #[test]
+    fn due_today_is_not_overdue_in_any_format() {
+        let me = me();
+        let config = ScoringConfig::default();
+        let today = Local::now().date_naive();
+        let due = |value: String| {
+            let mut target = issue(1, None);
+            target.assignee = Some(me.clone());
+            target.due_date = Some(value);
+            ScoringService::calculate_score_breakdown(&target, &me, &config).due_date
+        };
+
+        // 今日が期限なら期限間近（+50）で、期限切れ（+100）にはならない
+        for format in ["%Y-%m-%d", "%Y-%m-%dT00:00:00Z", "%Y-%m-%dT00:00:00-05:00"] {
+            assert_eq!(due(today.format(format).to_string()), 50, "format: {format}");
+        }
+        let yesterday = today - chrono::Duration::days(1);
+        assert_eq!(due(yesterday.format("%Y-%m-%dT00:00:00Z").to_string()), 100);
+    }
+
+    #[test]
+    fn parse_timezone_accepts_offsets_and_utc() {
+        let hours = |h: i32| FixedOffset::east_opt(h * 3600);
+        assert_eq!(parse_timezone("+09:00"), hours(9));
+        assert_eq!(parse_timezone(" UTC "), hours(0));
+        assert_eq!(parse_timezone("Z"), hours(0));
+        assert_eq!(parse_timezone("-05:30"), FixedOffset::west_opt(5 * 3600 + 30 * 60));
+        for invalid in ["", "Asia/Tokyo", "09:00", "+15:00", "+09:60", "+9"] {
+            assert_eq!(parse_timezone(invalid), None, "value: {invalid}");
+        }
+    }
+
+    #[test]
+    fn due_date_bonus_uses_configured_timezone_around_midnight() {
+        let due = NaiveDate::from_ymd_opt(2026, 6, 15).unwrap();
+        let jst = ScoringConfig::default().with_timezone(Some("+09:00"));
+        let utc = ScoringConfig::default().with_timezone(Some("UTC"));
+        let bonus = |config: &ScoringConfig, now: &str| {
+            let now = DateTime::parse_from_rfc3339(now).unwrap().with_timezone(&Utc);
+            ScoringService::due_date_bonus(due, config.today(now))
+        };
+
+        // JST では既に期限当日（UTC ではまだ前日）
+        assert_eq!(bonus(&jst, "2026-06-14T15:30:00Z"), 50);
+        assert_eq!(utc.today("2026-06-14T15:30:00Z".parse().unwrap()).to_string(), "2026-06-14");
+        // JST では期限翌日なので期限切れ、UTC ではまだ期限当日
+        assert_eq!(bonus(&jst, "2026-06-15T15:00:00Z"), 100);
+        assert_eq!(bonus(&utc, "2026-06-15T15:00:00Z"), 50);
     }
 }
@@ -12,6 +12,8 @@ export interface Issue {
   assignee?: { name: string }
   dueDate?: string
   updated?: string
+  /** 親課題ID（子課題の場合のみ） */
+  parentIssueId?: number | null
   relevance_score: number
   workspace_id: number
   /** AI生成の1行要約（ai_results.summary から LEFT JOIN） */
@@ -30,6 +32,21 @@ export interface Issue {
    * - undefined の場合は未取得（埋め込み基盤が未提供の経路）として扱う
    */
   embedding_ready?: boolean
+  /** 所属ワークスペースのBacklogドメイン（workspaces を JOIN して付与） */
+  workspace_domain?: string
+  /** 所属ワークスペースの表示名（ラベル）。未設定なら null */
+  workspace_label?: string | null
+  /** 添付ファイル（無ければ null または空配列） */
+  attachments?: { id: number; name: string; size: number }[] | null
+}
+
+/** get_issues の戻り値（課題一覧と最終同期時刻） */
+export interface IssuesResponse {
+  issues: Issue[]
+  /** 全ワークスペースで最も新しい同期時刻（RFC3339）。未同期なら null */
+  last_synced_at: string | null
+  /** ワークスペースIDごとの最終同期時刻（RFC3339） */
+  workspaces_last_synced: Record<number, string>
 }
 
 /**
@@ -38,6 +55,8 @@ export interface Issue {
 export function useIssues() {
   const issues = ref<Issue[]>([])
   const loading = ref(false)
+  /** 最終同期時刻（RFC3339）。未同期なら null */
+  const lastSyncedAt = ref<string | null>(null)
 
   /**
    * 課題一覧を取得
@@ -45,7 +64,9 @@ export function useIssues() {
   async function loadIssues() {
     loading.value = true
     try {
-      issues.value = await invoke<Issue[]>('get_issues')
+      const response = await invoke<IssuesResponse>('get_issues')
+      issues.value = response.issues
+      lastSyncedAt.value = response.last_synced_at
     } catch (e) {
       console.error('Failed to load issues:', e)
     } finally {
@@ -56,6 +77,7 @@ export function useIssues() {
   return {
     issues,
     loading,
+    lastSyncedAt,
     loadIssues,
     syncIssues,
   }